@@ -8,4 +8,25 @@ pub enum ContractError {
     AddressAlreadySet = 3,
     InvalidParameter = 4,
     Unauthorized = 5,
+    TokenAlreadySupported = 6,
+    TokenNotSupported = 7,
+    NoPendingAdmin = 8,
+    RoleAlreadyGranted = 9,
+    RoleNotGranted = 10,
+    TimelockRequired = 11,
+    ActionNotFound = 12,
+    TimelockNotElapsed = 13,
+    InvalidThreshold = 14,
+    NotSigner = 15,
+    SignerSetAlreadyConfigured = 16,
+    ProposalNotFound = 17,
+    AlreadyApproved = 18,
+    ThresholdNotReached = 19,
+    InvalidFeeTiers = 20,
+    InvalidFeeDistribution = 21,
+    InvalidVolumeLimit = 22,
+    PrimaryRelayer = 23,
+    SignerApprovalRequired = 24,
+    ProposalExpired = 25,
+    RotationInProgress = 26,
 }
@@ -0,0 +1,802 @@
+use crate::storage::{
+    FeeRecipient, FeeTier, GovernanceAction, PauseOperation, ProtocolAddressType, ProtocolConfig,
+    Role, VolumeLimit,
+};
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+/// Version carried by event payloads without a family version of their own; bumped
+/// whenever one of their shapes changes
+///
+/// - 1: Initial typed events
+pub const EVENT_VERSION: u32 = 1;
+
+/// Global protocol fee changed
+///
+/// Topics: `("ProtocolFeeUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolFeeUpdatedEvent {
+    pub version: u32,
+    pub protocol_fee_percent: i64,
+}
+
+impl ProtocolFeeUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ProtocolFeeUpdated"),), self);
+    }
+}
+
+/// Protocol fee change scheduled
+///
+/// Topics: `("ProtocolFeeScheduled", effective_at)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolFeeScheduledEvent {
+    pub version: u32,
+    pub effective_at: u64,
+    pub protocol_fee_percent: i64,
+}
+
+impl ProtocolFeeScheduledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "ProtocolFeeScheduled"), self.effective_at),
+            self,
+        );
+    }
+}
+
+/// Scheduled protocol fee change withdrawn
+///
+/// Topics: `("ScheduledFeeCancelled", effective_at)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledFeeCancelledEvent {
+    pub version: u32,
+    pub effective_at: u64,
+    pub protocol_fee_percent: i64,
+}
+
+impl ScheduledFeeCancelledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "ScheduledFeeCancelled"), self.effective_at),
+            self,
+        );
+    }
+}
+
+/// Volume fee tiers replaced
+///
+/// Topics: `("FeeTiersUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTiersUpdatedEvent {
+    pub version: u32,
+    pub tiers: Vec<FeeTier>,
+}
+
+impl FeeTiersUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "FeeTiersUpdated"),), self);
+    }
+}
+
+/// Per-token fee override set
+///
+/// Topics: `("TokenFeeUpdated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFeeUpdatedEvent {
+    pub version: u32,
+    pub token: Address,
+    pub protocol_fee_percent: i64,
+}
+
+impl TokenFeeUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "TokenFeeUpdated"), self.token.clone()),
+            self,
+        );
+    }
+}
+
+/// Per-token fee override removed
+///
+/// Topics: `("TokenFeeRemoved", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFeeRemovedEvent {
+    pub version: u32,
+    pub token: Address,
+}
+
+impl TokenFeeRemovedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "TokenFeeRemoved"), self.token.clone()),
+            self,
+        );
+    }
+}
+
+/// Per-token fee floor and cap set
+///
+/// Topics: `("TokenFeeBoundsUpdated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFeeBoundsUpdatedEvent {
+    pub version: u32,
+    pub token: Address,
+    pub min_fee: i128,
+    pub max_fee: i128,
+}
+
+impl TokenFeeBoundsUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "TokenFeeBoundsUpdated"),
+                self.token.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Per-token fee floor and cap removed
+///
+/// Topics: `("TokenFeeBoundsRemoved", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenFeeBoundsRemovedEvent {
+    pub version: u32,
+    pub token: Address,
+}
+
+impl TokenFeeBoundsRemovedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "TokenFeeBoundsRemoved"),
+                self.token.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Volume limit set; `token` is `None` for the global limit
+///
+/// Topics: `("VolumeLimitUpdated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeLimitUpdatedEvent {
+    pub version: u32,
+    pub token: Option<Address>,
+    pub limit: VolumeLimit,
+}
+
+impl VolumeLimitUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "VolumeLimitUpdated"), self.token.clone()),
+            self,
+        );
+    }
+}
+
+/// Volume limit removed; `token` is `None` for the global limit
+///
+/// Topics: `("VolumeLimitRemoved", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeLimitRemovedEvent {
+    pub version: u32,
+    pub token: Option<Address>,
+}
+
+impl VolumeLimitRemovedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "VolumeLimitRemoved"), self.token.clone()),
+            self,
+        );
+    }
+}
+
+/// Liquidity provider fee override set
+///
+/// Topics: `("LpFeeOverrideUpdated", liquidity_provider)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpFeeOverrideUpdatedEvent {
+    pub version: u32,
+    pub liquidity_provider: Address,
+    pub fee_percent: i64,
+    pub expires_at: Option<u64>,
+}
+
+impl LpFeeOverrideUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "LpFeeOverrideUpdated"),
+                self.liquidity_provider.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Liquidity provider fee override removed
+///
+/// Topics: `("LpFeeOverrideRemoved", liquidity_provider)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpFeeOverrideRemovedEvent {
+    pub version: u32,
+    pub liquidity_provider: Address,
+}
+
+impl LpFeeOverrideRemovedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "LpFeeOverrideRemoved"),
+                self.liquidity_provider.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Treasury or relayer address changed
+///
+/// Topics: `("ProtocolAddressUpdated", what)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolAddressUpdatedEvent {
+    pub version: u32,
+    pub what: ProtocolAddressType,
+    pub previous: Option<Address>,
+    pub new_value: Address,
+}
+
+impl ProtocolAddressUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "ProtocolAddressUpdated"), self.what),
+            self,
+        );
+    }
+}
+
+/// Core settings changed through `update_config`
+///
+/// Topics: `("ConfigUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdatedEvent {
+    pub version: u32,
+    pub old_config: ProtocolConfig,
+    pub new_config: ProtocolConfig,
+}
+
+impl ConfigUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ConfigUpdated"),), self);
+    }
+}
+
+/// Relayer rotated with a grace period for the previous key
+///
+/// Topics: `("RelayerRotated", previous)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelayerRotatedEvent {
+    pub version: u32,
+    pub previous: Address,
+    pub new_relayer: Address,
+    pub expires_at_ledger: u32,
+}
+
+impl RelayerRotatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "RelayerRotated"), self.previous.clone()),
+            self,
+        );
+    }
+}
+
+/// Protocol fee distribution replaced
+///
+/// Topics: `("FeeDistributionUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeDistributionUpdatedEvent {
+    pub version: u32,
+    pub recipients: Vec<FeeRecipient>,
+}
+
+impl FeeDistributionUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "FeeDistributionUpdated"),), self);
+    }
+}
+
+/// Global pause enabled
+///
+/// Topics: `("Paused",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausedEvent {
+    pub version: u32,
+}
+
+impl PausedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish((Symbol::new(env, "Paused"),), self);
+    }
+}
+
+/// Global pause lifted
+///
+/// Topics: `("Unpaused",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnpausedEvent {
+    pub version: u32,
+}
+
+impl UnpausedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish((Symbol::new(env, "Unpaused"),), self);
+    }
+}
+
+/// Single operation paused or resumed
+///
+/// Topics: `("OperationPauseUpdated", operation)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationPauseUpdatedEvent {
+    pub version: u32,
+    pub operation: PauseOperation,
+    pub paused: bool,
+}
+
+impl OperationPauseUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "OperationPauseUpdated"), self.operation),
+            self,
+        );
+    }
+}
+
+/// Token paused or resumed
+///
+/// Topics: `("TokenPauseUpdated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPauseUpdatedEvent {
+    pub version: u32,
+    pub token: Address,
+    pub paused: bool,
+}
+
+impl TokenPauseUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "TokenPauseUpdated"), self.token.clone()),
+            self,
+        );
+    }
+}
+
+/// Accounts added to (`denied`) or removed from the denylist
+///
+/// Topics: `("DenylistUpdated", denied)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenylistUpdatedEvent {
+    pub version: u32,
+    pub accounts: Vec<Address>,
+    pub denied: bool,
+}
+
+impl DenylistUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "DenylistUpdated"), self.denied), self);
+    }
+}
+
+/// Accounts added to (`allowed`) or removed from the allowlist
+///
+/// Topics: `("AllowlistUpdated", allowed)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistUpdatedEvent {
+    pub version: u32,
+    pub accounts: Vec<Address>,
+    pub allowed: bool,
+}
+
+impl AllowlistUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "AllowlistUpdated"), self.allowed), self);
+    }
+}
+
+/// Refund addresses approved or unapproved for denylisted senders
+///
+/// Topics: `("ApprovedRefundAddressesUpdated", approved)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApprovedRefundAddressesUpdatedEvent {
+    pub version: u32,
+    pub accounts: Vec<Address>,
+    pub approved: bool,
+}
+
+impl ApprovedRefundAddressesUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "ApprovedRefundAddressesUpdated"),
+                self.approved,
+            ),
+            self,
+        );
+    }
+}
+
+/// Allowlist-only mode toggled
+///
+/// Topics: `("AllowlistOnlyUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistOnlyUpdatedEvent {
+    pub version: u32,
+    pub enabled: bool,
+}
+
+impl AllowlistOnlyUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "AllowlistOnlyUpdated"),), self);
+    }
+}
+
+/// Token added to or removed from the supported set
+///
+/// Topics: `("TokenSupportUpdated", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSupportUpdatedEvent {
+    pub version: u32,
+    pub token: Address,
+    pub supported: bool,
+}
+
+impl TokenSupportUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "TokenSupportUpdated"), self.token.clone()),
+            self,
+        );
+    }
+}
+
+/// Admin transfer proposed
+///
+/// Topics: `("AdminTransferProposed", admin)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferProposedEvent {
+    pub version: u32,
+    pub admin: Address,
+    pub new_admin: Address,
+}
+
+impl AdminTransferProposedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "AdminTransferProposed"),
+                self.admin.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Admin transfer accepted
+///
+/// Topics: `("AdminTransferred", previous_admin)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferredEvent {
+    pub version: u32,
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+impl AdminTransferredEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "AdminTransferred"),
+                self.previous_admin.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Pending admin transfer cancelled
+///
+/// Topics: `("AdminTransferCancelled", admin)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferCancelledEvent {
+    pub version: u32,
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+impl AdminTransferCancelledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "AdminTransferCancelled"),
+                self.admin.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Role granted by the admin
+///
+/// Topics: `("RoleGranted", role)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrantedEvent {
+    pub version: u32,
+    pub role: Role,
+    pub account: Address,
+}
+
+impl RoleGrantedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "RoleGranted"), self.role), self);
+    }
+}
+
+/// Role revoked by the admin
+///
+/// Topics: `("RoleRevoked", role)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevokedEvent {
+    pub version: u32,
+    pub role: Role,
+    pub account: Address,
+}
+
+impl RoleRevokedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "RoleRevoked"), self.role), self);
+    }
+}
+
+/// Role given up by its holder
+///
+/// Topics: `("RoleRenounced", role)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRenouncedEvent {
+    pub version: u32,
+    pub role: Role,
+    pub account: Address,
+}
+
+impl RoleRenouncedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "RoleRenounced"), self.role), self);
+    }
+}
+
+/// Governance action queued behind the timelock
+///
+/// Topics: `("ActionQueued", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionQueuedEvent {
+    pub version: u32,
+    pub id: u64,
+    pub action: GovernanceAction,
+    pub eta: u64,
+}
+
+impl ActionQueuedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ActionQueued"), self.id), self);
+    }
+}
+
+/// Queued governance action applied
+///
+/// Topics: `("ActionExecuted", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionExecutedEvent {
+    pub version: u32,
+    pub id: u64,
+    pub action: GovernanceAction,
+}
+
+impl ActionExecutedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ActionExecuted"), self.id), self);
+    }
+}
+
+/// Queued governance action discarded
+///
+/// Topics: `("ActionCancelled", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionCancelledEvent {
+    pub version: u32,
+    pub id: u64,
+    pub action: GovernanceAction,
+}
+
+impl ActionCancelledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ActionCancelled"), self.id), self);
+    }
+}
+
+/// Multisig signer set and threshold stored
+///
+/// Topics: `("SignerSetUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerSetUpdatedEvent {
+    pub version: u32,
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+impl SignerSetUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "SignerSetUpdated"),), self);
+    }
+}
+
+/// Signer proposal created; the proposer's approval is counted
+///
+/// Topics: `("ProposalCreated", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreatedEvent {
+    pub version: u32,
+    pub id: u64,
+    pub signer: Address,
+    pub action: GovernanceAction,
+}
+
+impl ProposalCreatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ProposalCreated"), self.id), self);
+    }
+}
+
+/// Signer approval added to a proposal
+///
+/// Topics: `("ProposalApproved", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalApprovedEvent {
+    pub version: u32,
+    pub id: u64,
+    pub signer: Address,
+    pub approvals: u32,
+}
+
+impl ProposalApprovedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ProposalApproved"), self.id), self);
+    }
+}
+
+/// Signer proposal executed or queued behind the timelock
+///
+/// Topics: `("ProposalExecuted", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecutedEvent {
+    pub version: u32,
+    pub id: u64,
+    pub action: GovernanceAction,
+}
+
+impl ProposalExecutedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ProposalExecuted"), self.id), self);
+    }
+}
+
+/// Signer proposal withdrawn by its proposer
+///
+/// Topics: `("ProposalCancelled", id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCancelledEvent {
+    pub version: u32,
+    pub id: u64,
+    pub signer: Address,
+}
+
+impl ProposalCancelledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "ProposalCancelled"), self.id), self);
+    }
+}
+
+/// Maximum order lifetime changed
+///
+/// Topics: `("MaxOrderDurationUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaxOrderDurationUpdatedEvent {
+    pub version: u32,
+    pub max_order_duration: u64,
+}
+
+impl MaxOrderDurationUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "MaxOrderDurationUpdated"),), self);
+    }
+}
+
+/// Fee charged on sender cancellations changed
+///
+/// Topics: `("CancellationFeeUpdated",)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancellationFeeUpdatedEvent {
+    pub version: u32,
+    pub fee_percent: i64,
+}
+
+impl CancellationFeeUpdatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events()
+            .publish((Symbol::new(env, "CancellationFeeUpdated"),), self);
+    }
+}
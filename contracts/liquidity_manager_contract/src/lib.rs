@@ -1,4 +1,5 @@
 #![no_std]
-mod error;
+pub mod error;
+pub mod events;
 pub mod liquidity_manager;
 pub mod storage;
@@ -1,8 +1,42 @@
 use crate::{
     error::ContractError,
-    storage::{DataKey, ProtocolAddressType},
+    events::{
+        ActionCancelledEvent, ActionExecutedEvent, ActionQueuedEvent, AdminTransferCancelledEvent,
+        AdminTransferProposedEvent, AdminTransferredEvent, AllowlistOnlyUpdatedEvent,
+        AllowlistUpdatedEvent, ApprovedRefundAddressesUpdatedEvent, CancellationFeeUpdatedEvent,
+        ConfigUpdatedEvent, DenylistUpdatedEvent, FeeDistributionUpdatedEvent,
+        FeeTiersUpdatedEvent, LpFeeOverrideRemovedEvent, LpFeeOverrideUpdatedEvent,
+        MaxOrderDurationUpdatedEvent, OperationPauseUpdatedEvent, PausedEvent,
+        ProposalApprovedEvent, ProposalCancelledEvent, ProposalCreatedEvent, ProposalExecutedEvent,
+        ProtocolAddressUpdatedEvent, ProtocolFeeScheduledEvent, ProtocolFeeUpdatedEvent,
+        RelayerRotatedEvent, RoleGrantedEvent, RoleRenouncedEvent, RoleRevokedEvent,
+        ScheduledFeeCancelledEvent, SignerSetUpdatedEvent, TokenFeeBoundsRemovedEvent,
+        TokenFeeBoundsUpdatedEvent, TokenFeeRemovedEvent, TokenFeeUpdatedEvent,
+        TokenPauseUpdatedEvent, TokenSupportUpdatedEvent, UnpausedEvent, VolumeLimitRemovedEvent,
+        VolumeLimitUpdatedEvent, EVENT_VERSION,
+    },
+    storage::{
+        DataKey, FeeBounds, FeeRecipient, FeeTier, GovernanceAction, LpFeeOverride,
+        MultisigProposal, PauseOperation, PauseState, ProtocolAddressType, ProtocolConfig,
+        ProtocolConfigUpdate, QueuedAction, RetiringRelayer, Role, ScheduledFee, VolumeLimit,
+    },
 };
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+/// Longest timelock delay governance may configure (30 days), so queued changes stay executable
+pub const MAX_TIMELOCK_DELAY: u64 = 30 * 24 * 60 * 60;
+
+/// How long a signer proposal stays open for approval and execution (7 days)
+pub const PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/// Order lifetime cap applied until `set_max_order_duration` is called (30 days)
+pub const DEFAULT_MAX_ORDER_DURATION: u64 = 30 * 24 * 60 * 60;
+
+/// Longest order lifetime governance may configure (365 days)
+pub const MAX_ORDER_DURATION: u64 = 365 * 24 * 60 * 60;
+
+/// Longest volume limit window governance may configure (30 days)
+pub const MAX_VOLUME_WINDOW: u64 = 30 * 24 * 60 * 60;
 
 /// # Liquidity Provider Settings Manager Contract
 ///
@@ -10,14 +44,24 @@ use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
 /// Manages protocol-wide settings, fees, and administrative functions for the LP system.
 ///
 /// ## Key Responsibilities:
-/// - Protocol fee configuration and management
+/// - Protocol fee configuration and management, including scheduled fee changes
+///   volume-tiered fees, per-token fee floors/caps and negotiated LP fees
 /// - Treasury and relayer address management  
-/// - Contract pausing/unpausing
-/// - Administrative access control
+/// - Fee distribution across multiple recipients
+/// - Supported token registry
+/// - Timelocked governance queue for sensitive changes
+/// - M-of-N signer approval as an alternative to the single admin key
+/// - Contract pausing/unpausing, globally, per operation and per token
+/// - Administrative and role-based access control
 ///
 /// ## Security Model:
-/// - **Admin**: Full control over all settings
-/// - **Relayer**: Authorized to execute settlements/refunds
+/// - **Admin**: Full control over all settings; implicitly holds every management role
+///   until signers are registered, after which admin-only changes need signer approval
+/// - **FeeManager**: May update protocol and per-token fees
+/// - **Pauser**: May pause/unpause the protocol
+/// - **Upgrader**: May upgrade the settings manager WASM
+/// - **Relayer**: Authorized to execute settlements/refunds (multiple holders allowed)
+/// - **Signers**: Once registered, replace the admin; any threshold of signers can apply governance actions
 /// - **Treasury**: Receives protocol fees
 #[contract]
 pub struct LPSettingManagerContract;
@@ -39,7 +83,7 @@ impl LPSettingManagerContract {
     ///
     /// ## Parameters:
     /// - `treasury`: Address to receive protocol fees
-    /// - `relayer_address`: Authorized address for settlement operations
+    /// - `relayer_address`: Authorized address for settlement operations (granted the Relayer role)
     pub fn initialize(env: Env, admin: Address, treasury: Address, relayer_address: Address) {
         let storage = env.storage().persistent();
 
@@ -68,6 +112,56 @@ impl LPSettingManagerContract {
         env.storage()
             .persistent()
             .set(&DataKey::Relayer, &relayer_address);
+
+        let mut relayers = Vec::new(&env);
+        relayers.push_back(relayer_address);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RoleMembers(Role::Relayer), &relayers);
+    }
+
+    /// # Internal: Require Role
+    ///
+    /// ## Description:
+    /// Authenticates `caller` and checks that it is the admin or holds `role`.
+    ///
+    /// ## Note:
+    /// - Once signers are configured the admin only passes if it explicitly holds `role`
+    fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if Self::has_role(env.clone(), role, caller.clone()) {
+            return Ok(());
+        }
+
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        if *caller == admin {
+            Self::require_no_signers(env)?;
+            return Ok(());
+        }
+
+        Err(ContractError::Unauthorized)
+    }
+
+    /// # Internal: Require Admin
+    ///
+    /// ## Description:
+    /// Authenticates the admin for an admin-only entry point and returns its address.
+    /// Fails with `SignerApprovalRequired` once signers are configured; the signers
+    /// then act through `propose` instead.
+    fn require_admin(env: &Env) -> Result<Address, ContractError> {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        Self::require_no_signers(env)?;
+        Ok(admin)
+    }
+
+    /// # Internal: Require No Signers
+    fn require_no_signers(env: &Env) -> Result<(), ContractError> {
+        if env.storage().persistent().has(&DataKey::Signers) {
+            return Err(ContractError::SignerApprovalRequired);
+        }
+        Ok(())
     }
 
     /// # Update Protocol Fee Percentage
@@ -76,210 +170,2666 @@ impl LPSettingManagerContract {
     /// Changes the protocol fee percentage applied to all orders.
     ///
     /// ## Authorization:
-    /// - `admin`: Must authorize the change
+    /// - `caller`: Admin or FeeManager, must authorize the change
     ///
     /// ## Validation:
     /// - Fee must be between 0 and 100,000 basis points (0% to 100%)
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
     ///
     /// ## Events:
-    /// - `("ProtocolFeeUpdated",)` with new fee percentage
+    /// - `ProtocolFeeUpdatedEvent` under `("ProtocolFeeUpdated",)`
     ///
     /// ## Parameters:
+    /// - `caller`: Address performing the update
     /// - `protocol_fee_percent`: New fee in basis points (e.g., 1000 = 1%)
-    pub fn update_protocol_fee(env: Env, protocol_fee_percent: i64) -> Result<(), ContractError> {
-        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    pub fn update_protocol_fee(
+        env: Env,
+        caller: Address,
+        protocol_fee_percent: i64,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_protocol_fee(&env, protocol_fee_percent)
+    }
 
+    /// # Internal: Apply Protocol Fee
+    ///
+    /// ## Description:
+    /// Validates and stores the global protocol fee. Shared by the direct and timelocked paths.
+    fn apply_protocol_fee(env: &Env, protocol_fee_percent: i64) -> Result<(), ContractError> {
         // Validate fee is within reasonable bounds (0% to 100%)
         if !(0..=100_000).contains(&protocol_fee_percent) {
             return Err(ContractError::InvalidFeePercent);
         }
 
+        // Scheduled fees already in force are superseded by this update
+        Self::fold_scheduled_fees(env);
         env.storage()
             .persistent()
             .set(&DataKey::ProtocolFeePercent, &protocol_fee_percent);
-        env.events()
-            .publish(("ProtocolFeeUpdated",), protocol_fee_percent);
+        ProtocolFeeUpdatedEvent {
+            version: EVENT_VERSION,
+            protocol_fee_percent,
+        }
+        .publish(env);
         Ok(())
     }
 
-    /// # Internal: Update Protocol Address
+    /// # Schedule Protocol Fee Change
     ///
     /// ## Description:
-    /// Helper function to update protocol addresses with validation and event emission.
+    /// Announces a new global protocol fee that takes effect at `effective_at`
+    /// without further action. `get_fee_details` switches over at that ledger timestamp.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the change
     ///
     /// ## Validation:
-    /// - New address must be different from current address
+    /// - Fee must be between 0 and 100,000 basis points (0% to 100%)
+    /// - `effective_at` must be in the future and not already scheduled
+    /// - With a timelock configured, `effective_at` must be at least the delay away
     ///
     /// ## Events:
-    /// - `("ProtocolAddressUpdated", address_type)` with new address
-    fn update_address(
-        env: &Env,
-        key: DataKey,
-        what: ProtocolAddressType,
-        new_value: Address,
+    /// - `ProtocolFeeScheduledEvent` under `("ProtocolFeeScheduled", effective_at)`
+    pub fn schedule_protocol_fee(
+        env: Env,
+        caller: Address,
+        protocol_fee_percent: i64,
+        effective_at: u64,
     ) -> Result<(), ContractError> {
-        // Prevent setting the same address
-        if let Some(current) = env.storage().persistent().get::<_, Address>(&key) {
-            if current == new_value {
-                return Err(ContractError::AddressAlreadySet);
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        if !(0..=100_000).contains(&protocol_fee_percent) {
+            return Err(ContractError::InvalidFeePercent);
+        }
+
+        let now = env.ledger().timestamp();
+        if effective_at <= now {
+            return Err(ContractError::InvalidParameter);
+        }
+        if effective_at < now + Self::get_timelock_delay(env.clone()) {
+            return Err(ContractError::TimelockRequired);
+        }
+
+        Self::fold_scheduled_fees(&env);
+        let mut schedule = Self::get_scheduled_fees(env.clone());
+
+        // Keep the schedule ordered by effective time
+        let mut index = schedule.len();
+        for (position, scheduled) in schedule.iter().enumerate() {
+            if scheduled.effective_at == effective_at {
+                return Err(ContractError::InvalidParameter);
+            }
+            if scheduled.effective_at > effective_at {
+                index = position as u32;
+                break;
             }
         }
+        schedule.insert(
+            index,
+            ScheduledFee {
+                fee_percent: protocol_fee_percent,
+                effective_at,
+            },
+        );
 
-        env.storage().persistent().set(&key, &new_value);
-        env.events()
-            .publish(("ProtocolAddressUpdated", what), new_value);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScheduledFees, &schedule);
+        ProtocolFeeScheduledEvent {
+            version: EVENT_VERSION,
+            effective_at,
+            protocol_fee_percent,
+        }
+        .publish(&env);
         Ok(())
     }
 
-    /// # Update Protocol Addresses
+    /// # Cancel Scheduled Fee Change
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the cancellation
+    ///
+    /// ## Validation:
+    /// - A fee change must be scheduled for `effective_at` and not yet be in force
+    ///
+    /// ## Events:
+    /// - `ScheduledFeeCancelledEvent` under `("ScheduledFeeCancelled", effective_at)`
+    pub fn cancel_scheduled_fee(
+        env: Env,
+        caller: Address,
+        effective_at: u64,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+
+        Self::fold_scheduled_fees(&env);
+        let mut schedule = Self::get_scheduled_fees(env.clone());
+        let index = schedule
+            .iter()
+            .position(|scheduled| scheduled.effective_at == effective_at)
+            .ok_or(ContractError::InvalidParameter)? as u32;
+        let cancelled = schedule.get(index).unwrap();
+        schedule.remove(index);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ScheduledFees, &schedule);
+        ScheduledFeeCancelledEvent {
+            version: EVENT_VERSION,
+            effective_at,
+            protocol_fee_percent: cancelled.fee_percent,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Internal: Fold Scheduled Fees
     ///
     /// ## Description:
-    /// Updates treasury or relayer addresses with proper validation.
+    /// Moves scheduled fees whose effective time has passed into the stored base fee.
+    fn fold_scheduled_fees(env: &Env) {
+        let mut schedule: Vec<ScheduledFee> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScheduledFees)
+            .unwrap_or(Vec::new(env));
+        let now = env.ledger().timestamp();
+        let mut changed = false;
+
+        while let Some(next) = schedule.first() {
+            if next.effective_at > now {
+                break;
+            }
+            env.storage()
+                .persistent()
+                .set(&DataKey::ProtocolFeePercent, &next.fee_percent);
+            schedule.pop_front();
+            changed = true;
+        }
+
+        if changed {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ScheduledFees, &schedule);
+        }
+    }
+
+    /// # Set Volume Fee Tiers
+    ///
+    /// ## Description:
+    /// Replaces the flat global fee with a schedule keyed by order amount. Orders whose
+    /// amount falls outside every tier, or tokens with their own fee, are unaffected.
+    /// Pass an empty list to return to the flat fee.
     ///
     /// ## Authorization:
-    /// - `admin`: Must authorize the change
+    /// - `caller`: Admin or FeeManager, must authorize the change
     ///
-    /// ## Parameters:
-    /// - `what`: Type of address to update (Treasury or Aggregator/Relayer)
-    /// - `value`: New address value
-    pub fn update_protocol_address(
+    /// ## Validation:
+    /// - Tiers must be sorted, non-overlapping and have `0 ≤ min_amount < max_amount`
+    /// - Each tier fee must be between 0 and 100,000 basis points
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `FeeTiersUpdatedEvent` under `("FeeTiersUpdated",)`
+    pub fn set_fee_tiers(
         env: Env,
-        what: ProtocolAddressType,
-        value: Address,
+        caller: Address,
+        tiers: Vec<FeeTier>,
     ) -> Result<(), ContractError> {
-        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
 
-        match what {
-            ProtocolAddressType::Treasury => {
-                Self::update_address(&env, DataKey::Treasury, what, value)
+        Self::apply_fee_tiers(&env, tiers)
+    }
+
+    /// # Internal: Validate Fee Tiers
+    fn validate_fee_tiers(tiers: &Vec<FeeTier>) -> Result<(), ContractError> {
+        let mut previous_max: i128 = 0;
+        for tier in tiers.iter() {
+            if tier.min_amount < previous_max || tier.min_amount >= tier.max_amount {
+                return Err(ContractError::InvalidFeeTiers);
             }
-            ProtocolAddressType::Aggregator => {
-                Self::update_address(&env, DataKey::Relayer, what, value)
+            if !(0..=100_000).contains(&tier.fee_percent) {
+                return Err(ContractError::InvalidFeePercent);
             }
+            previous_max = tier.max_amount;
         }
+        Ok(())
     }
 
-    /// # Pause Contract Operations
+    /// # Internal: Apply Fee Tiers
+    fn apply_fee_tiers(env: &Env, tiers: Vec<FeeTier>) -> Result<(), ContractError> {
+        Self::validate_fee_tiers(&tiers)?;
+
+        env.storage().persistent().set(&DataKey::FeeTiers, &tiers);
+        FeeTiersUpdatedEvent {
+            version: EVENT_VERSION,
+            tiers,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Set Per-Token Protocol Fee
     ///
     /// ## Description:
-    /// Emergency function to pause all order creation and settlements.
+    /// Overrides the global protocol fee for orders denominated in `token`.
     ///
     /// ## Authorization:
-    /// - `admin`: Must authorize the pause
+    /// - `caller`: Admin or FeeManager, must authorize the change
     ///
-    /// ## Events:
-    /// - `("Paused",)` when contract is paused
+    /// ## Validation:
+    /// - Fee must be between 0 and 100,000 basis points (0% to 100%)
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
     ///
-    /// ## Note:
-    /// - Prevents new order creation
-    /// - Existing orders can still be settled/refunded
-    pub fn pause(env: Env) -> Result<(), ContractError> {
-        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage().persistent().set(&DataKey::Paused, &true);
-        env.events().publish(("Paused",), ());
+    /// ## Events:
+    /// - `TokenFeeUpdatedEvent` under `("TokenFeeUpdated", token)`
+    pub fn set_token_fee(
+        env: Env,
+        caller: Address,
+        token: Address,
+        protocol_fee_percent: i64,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_token_fee(&env, token, protocol_fee_percent)
+    }
+
+    /// # Internal: Apply Per-Token Protocol Fee
+    fn apply_token_fee(
+        env: &Env,
+        token: Address,
+        protocol_fee_percent: i64,
+    ) -> Result<(), ContractError> {
+        if !(0..=100_000).contains(&protocol_fee_percent) {
+            return Err(ContractError::InvalidFeePercent);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::TokenFeePercent(token.clone()),
+            &protocol_fee_percent,
+        );
+        TokenFeeUpdatedEvent {
+            version: EVENT_VERSION,
+            token,
+            protocol_fee_percent,
+        }
+        .publish(env);
         Ok(())
     }
 
-    /// # Unpause Contract Operations
+    /// # Set Cancellation Fee
     ///
     /// ## Description:
-    /// Resumes normal contract operations after a pause.
+    /// Sets the fee, in basis points of the order amount, charged when a sender
+    /// cancels an untouched order through the LP contract's `cancel_order`.
     ///
     /// ## Authorization:
-    /// - `admin`: Must authorize the unpause
+    /// - `caller`: Admin or FeeManager, must authorize the change
+    ///
+    /// ## Validation:
+    /// - Fee must be between 0 and 100,000 basis points (0% to 100%)
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
     ///
     /// ## Events:
-    /// - `("Unpaused",)` when contract is unpaused
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
-        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage().persistent().set(&DataKey::Paused, &false);
-        env.events().publish(("Unpaused",), ());
-        Ok(())
+    /// - `CancellationFeeUpdatedEvent` under `("CancellationFeeUpdated",)`
+    pub fn set_cancellation_fee(
+        env: Env,
+        caller: Address,
+        fee_percent: i64,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_cancellation_fee(&env, fee_percent)
     }
 
-    // ========== VIEW FUNCTIONS ==========
+    /// # Internal: Apply Cancellation Fee
+    fn apply_cancellation_fee(env: &Env, fee_percent: i64) -> Result<(), ContractError> {
+        if !(0..=100_000).contains(&fee_percent) {
+            return Err(ContractError::InvalidFeePercent);
+        }
 
-    /// # Get Current Fee Details
-    ///
-    /// ## Returns:
-    /// - Tuple of (protocol_fee_percent, max_bps)
-    /// - Default: (1000, 100000) = 1% fee
-    pub fn get_fee_details(env: Env) -> (i64, i64) {
-        let protocol_fee_percent: i64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ProtocolFeePercent)
-            .unwrap_or(0);
-        let max_bps: i64 = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&DataKey::MaxBps)
-            .unwrap_or(100_000);
-        (protocol_fee_percent, max_bps)
+            .set(&DataKey::CancellationFeePercent, &fee_percent);
+        CancellationFeeUpdatedEvent {
+            version: EVENT_VERSION,
+            fee_percent,
+        }
+        .publish(env);
+        Ok(())
     }
 
-    /// # Get Treasury Address
+    /// # Remove Per-Token Protocol Fee
     ///
-    /// ## Returns:
-    /// - Current treasury address for fee collection
-    pub fn get_treasury_address(env: Env) -> Address {
-        env.storage().persistent().get(&DataKey::Treasury).unwrap()
+    /// ## Description:
+    /// Clears a token fee override so the token falls back to the global fee.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the change
+    ///
+    /// ## Validation:
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `TokenFeeRemovedEvent` under `("TokenFeeRemoved", token)`
+    pub fn remove_token_fee(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_remove_token_fee(&env, token)
     }
 
-    /// # Get Relayer Address
-    ///
-    /// ## Returns:
-    /// - Current relayer address authorized for settlements
-    pub fn get_relayer_address(env: Env) -> Address {
-        env.storage().persistent().get(&DataKey::Relayer).unwrap()
+    /// # Internal: Apply Per-Token Protocol Fee Removal
+    fn apply_remove_token_fee(env: &Env, token: Address) -> Result<(), ContractError> {
+        let key = DataKey::TokenFeePercent(token.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        env.storage().persistent().remove(&key);
+        TokenFeeRemovedEvent {
+            version: EVENT_VERSION,
+            token,
+        }
+        .publish(env);
+        Ok(())
     }
 
-    /// # Check if Contract is Paused
+    /// # Set Per-Token Fee Bounds
     ///
-    /// ## Returns:
-    /// - `true` if contract operations are paused
-    /// - `false` if contract is operational
-    pub fn is_paused(env: Env) -> bool {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Paused)
-            .unwrap_or(false)
+    /// ## Description:
+    /// Sets an absolute floor and cap, in token units, on the protocol fee of an
+    /// order in `token`. Applied by the LP contract after the percentage fee.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the change
+    ///
+    /// ## Validation:
+    /// - `0 ≤ min_fee ≤ max_fee`
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `TokenFeeBoundsUpdatedEvent` under `("TokenFeeBoundsUpdated", token)`
+    pub fn set_token_fee_bounds(
+        env: Env,
+        caller: Address,
+        token: Address,
+        min_fee: i128,
+        max_fee: i128,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_token_fee_bounds(&env, token, min_fee, max_fee)
     }
 
-    /// # Check if Token is Supported
+    /// # Internal: Apply Per-Token Fee Bounds
+    fn apply_token_fee_bounds(
+        env: &Env,
+        token: Address,
+        min_fee: i128,
+        max_fee: i128,
+    ) -> Result<(), ContractError> {
+        if min_fee < 0 || min_fee > max_fee {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::TokenFeeBounds(token.clone()),
+            &FeeBounds { min_fee, max_fee },
+        );
+        TokenFeeBoundsUpdatedEvent {
+            version: EVENT_VERSION,
+            token,
+            min_fee,
+            max_fee,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Remove Per-Token Fee Bounds
     ///
-    /// ## Description:
-    /// Currently hardcoded to only support USDC. Can be extended for multi-token support.
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the change
     ///
-    /// ## Returns:
-    /// - `true` if token is supported for orders
-    /// - `false` if token is not supported
-    pub fn is_token_supported(env: Env, token: Address) -> bool {
-        env.storage()
-            .persistent()
-            .get(&DataKey::TokenSupported(token))
-            .unwrap_or(false)
+    /// ## Validation:
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `TokenFeeBoundsRemovedEvent` under `("TokenFeeBoundsRemoved", token)`
+    pub fn remove_token_fee_bounds(
+        env: Env,
+        caller: Address,
+        token: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_remove_token_fee_bounds(&env, token)
     }
 
-    /// # Upgrade Settings Manager WASM
+    /// # Internal: Apply Per-Token Fee Bounds Removal
+    fn apply_remove_token_fee_bounds(env: &Env, token: Address) -> Result<(), ContractError> {
+        let key = DataKey::TokenFeeBounds(token.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        env.storage().persistent().remove(&key);
+        TokenFeeBoundsRemovedEvent {
+            version: EVENT_VERSION,
+            token,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Set Volume Limit
     ///
     /// ## Description:
-    /// Updates the settings manager contract's WASM code.
+    /// Caps the order volume the LP contract accepts per window, either for one
+    /// `token` or globally across all tokens when `token` is `None`.
     ///
     /// ## Authorization:
-    /// - `admin`: Must authorize the upgrade
+    /// - `caller`: Admin or Pauser, must authorize the change
     ///
-    /// ## Note:
-    /// - Maintains all existing settings and state
-    /// - Only admin can perform upgrades
-    pub fn upgrade_lp_manager(e: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = e.storage().persistent().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
+    /// ## Validation:
+    /// - `max_volume > 0` and `0 < window_seconds ≤ MAX_VOLUME_WINDOW`
+    /// - `0 < auto_pause_at ≤ max_volume` when set
+    ///
+    /// ## Events:
+    /// - `VolumeLimitUpdatedEvent` under `("VolumeLimitUpdated", token)`
+    pub fn set_volume_limit(
+        env: Env,
+        caller: Address,
+        token: Option<Address>,
+        limit: VolumeLimit,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Pauser)?;
+
+        if limit.max_volume <= 0
+            || limit.window_seconds == 0
+            || limit.window_seconds > MAX_VOLUME_WINDOW
+        {
+            return Err(ContractError::InvalidVolumeLimit);
+        }
+        if let Some(threshold) = limit.auto_pause_at {
+            if threshold <= 0 || threshold > limit.max_volume {
+                return Err(ContractError::InvalidVolumeLimit);
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::VolumeLimit(token.clone()), &limit);
+        VolumeLimitUpdatedEvent {
+            version: EVENT_VERSION,
+            token,
+            limit,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Remove Volume Limit
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Pauser, must authorize the change
+    ///
+    /// ## Events:
+    /// - `VolumeLimitRemovedEvent` under `("VolumeLimitRemoved", token)`
+    pub fn remove_volume_limit(
+        env: Env,
+        caller: Address,
+        token: Option<Address>,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Pauser)?;
+
+        let key = DataKey::VolumeLimit(token.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        env.storage().persistent().remove(&key);
+        VolumeLimitRemovedEvent {
+            version: EVENT_VERSION,
+            token,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Set Liquidity Provider Fee Override
+    ///
+    /// ## Description:
+    /// Stores negotiated fee terms for a strategic liquidity provider. The LP contract
+    /// applies them instead of the regular fee when settling to that provider.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the change
+    ///
+    /// ## Validation:
+    /// - Fee must be between 0 and 100,000 basis points (0% to 100%)
+    /// - `expires_at`, when set, must be in the future
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `LpFeeOverrideUpdatedEvent` under `("LpFeeOverrideUpdated", liquidity_provider)`
+    pub fn set_lp_fee_override(
+        env: Env,
+        caller: Address,
+        liquidity_provider: Address,
+        fee_percent: i64,
+        expires_at: Option<u64>,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_lp_fee_override(&env, liquidity_provider, fee_percent, expires_at)
+    }
+
+    /// # Internal: Apply Liquidity Provider Fee Override
+    fn apply_lp_fee_override(
+        env: &Env,
+        liquidity_provider: Address,
+        fee_percent: i64,
+        expires_at: Option<u64>,
+    ) -> Result<(), ContractError> {
+        if !(0..=100_000).contains(&fee_percent) {
+            return Err(ContractError::InvalidFeePercent);
+        }
+
+        if let Some(expiry) = expires_at {
+            if expiry <= env.ledger().timestamp() {
+                return Err(ContractError::InvalidParameter);
+            }
+        }
+
+        env.storage().persistent().set(
+            &DataKey::LpFeeOverride(liquidity_provider.clone()),
+            &LpFeeOverride {
+                fee_percent,
+                expires_at,
+            },
+        );
+        LpFeeOverrideUpdatedEvent {
+            version: EVENT_VERSION,
+            liquidity_provider,
+            fee_percent,
+            expires_at,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Remove Liquidity Provider Fee Override
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or FeeManager, must authorize the change
+    ///
+    /// ## Validation:
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `LpFeeOverrideRemovedEvent` under `("LpFeeOverrideRemoved", liquidity_provider)`
+    pub fn remove_lp_fee_override(
+        env: Env,
+        caller: Address,
+        liquidity_provider: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::FeeManager)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_remove_lp_fee_override(&env, liquidity_provider)
+    }
+
+    /// # Internal: Apply Liquidity Provider Fee Override Removal
+    fn apply_remove_lp_fee_override(
+        env: &Env,
+        liquidity_provider: Address,
+    ) -> Result<(), ContractError> {
+        let key = DataKey::LpFeeOverride(liquidity_provider.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        env.storage().persistent().remove(&key);
+        LpFeeOverrideRemovedEvent {
+            version: EVENT_VERSION,
+            liquidity_provider,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Internal: Update Protocol Address
+    ///
+    /// ## Description:
+    /// Helper function to update protocol addresses with validation and event emission.
+    ///
+    /// ## Validation:
+    /// - New address must be different from current address
+    ///
+    /// ## Events:
+    /// - `ProtocolAddressUpdatedEvent` under `("ProtocolAddressUpdated", address_type)`
+    fn update_address(
+        env: &Env,
+        key: DataKey,
+        what: ProtocolAddressType,
+        new_value: Address,
+    ) -> Result<(), ContractError> {
+        // Prevent setting the same address
+        let previous: Option<Address> = env.storage().persistent().get(&key);
+        if previous.as_ref() == Some(&new_value) {
+            return Err(ContractError::AddressAlreadySet);
+        }
+
+        env.storage().persistent().set(&key, &new_value);
+        ProtocolAddressUpdatedEvent {
+            version: EVENT_VERSION,
+            what,
+            previous,
+            new_value,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Update Protocol Addresses
+    ///
+    /// ## Description:
+    /// Updates treasury or relayer addresses with proper validation.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the change
+    ///
+    /// ## Parameters:
+    /// - `what`: Type of address to update (Treasury or Aggregator/Relayer)
+    /// - `value`: New address value
+    ///
+    /// ## Note:
+    /// - Updating the Aggregator moves the Relayer role from the old address to the new one
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    pub fn update_protocol_address(
+        env: Env,
+        what: ProtocolAddressType,
+        value: Address,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_protocol_address(&env, what, value)
+    }
+
+    /// # Internal: Apply Protocol Address
+    ///
+    /// ## Description:
+    /// Routes an address update to the right storage key. Shared by the direct and timelocked paths.
+    fn apply_protocol_address(
+        env: &Env,
+        what: ProtocolAddressType,
+        value: Address,
+    ) -> Result<(), ContractError> {
+        match what {
+            ProtocolAddressType::Treasury => {
+                Self::update_address(env, DataKey::Treasury, what, value)
+            }
+            ProtocolAddressType::Aggregator => {
+                let previous: Address = env.storage().persistent().get(&DataKey::Relayer).unwrap();
+                Self::update_address(env, DataKey::Relayer, what, value.clone())?;
+                Self::move_relayer_role(env, &previous, value);
+                Ok(())
+            }
+        }
+    }
+
+    /// # Internal: Move Relayer Role
+    ///
+    /// ## Description:
+    /// Keeps the Relayer role in step with the primary relayer address. The previous
+    /// relayer keeps the role if it was granted explicitly before becoming primary.
+    fn move_relayer_role(env: &Env, previous: &Address, value: Address) {
+        let storage = env.storage().persistent();
+        let mut relayers = Self::get_role_members(env.clone(), Role::Relayer);
+        let previous_granted = storage.has(&DataKey::RelayerRoleGranted);
+        if !previous_granted {
+            if let Some(index) = relayers.first_index_of(previous) {
+                relayers.remove(index);
+            }
+        }
+
+        if relayers.contains(&value) {
+            storage.set(&DataKey::RelayerRoleGranted, &true);
+        } else {
+            storage.remove(&DataKey::RelayerRoleGranted);
+            relayers.push_back(value);
+        }
+        storage.set(&DataKey::RoleMembers(Role::Relayer), &relayers);
+    }
+
+    /// # Update Protocol Config
+    ///
+    /// ## Description:
+    /// Applies a partial update of the core settings in a single transaction, so a
+    /// deployment never observes a half-applied configuration.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the update
+    ///
+    /// ## Validation:
+    /// - At least one field must be set
+    /// - Fee must be between 0 and 100,000 basis points (0% to 100%)
+    /// - Supported tokens must not contain duplicates
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `ConfigUpdatedEvent` under `("ConfigUpdated",)`; the per-setting events are not emitted
+    ///
+    /// ## Note:
+    /// - A new relayer takes over the Relayer role from the previous one immediately
+    /// - `max_bps` is fixed at initialization and cannot be updated
+    pub fn update_config(env: Env, update: ProtocolConfigUpdate) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_config_update(&env, update)
+    }
+
+    /// # Internal: Validate Protocol Config Update
+    fn validate_config_update(update: &ProtocolConfigUpdate) -> Result<(), ContractError> {
+        if update.protocol_fee_percent.is_none()
+            && update.treasury.is_none()
+            && update.relayer.is_none()
+            && update.paused.is_none()
+            && update.supported_tokens.is_none()
+        {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        if let Some(fee) = update.protocol_fee_percent {
+            if !(0..=100_000).contains(&fee) {
+                return Err(ContractError::InvalidFeePercent);
+            }
+        }
+
+        if let Some(tokens) = &update.supported_tokens {
+            for (index, token) in tokens.iter().enumerate() {
+                if tokens.first_index_of(&token) != Some(index as u32) {
+                    return Err(ContractError::InvalidParameter);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// # Internal: Apply Protocol Config Update
+    fn apply_config_update(env: &Env, update: ProtocolConfigUpdate) -> Result<(), ContractError> {
+        Self::validate_config_update(&update)?;
+
+        let old_config = Self::get_config(env.clone());
+        let storage = env.storage().persistent();
+
+        if let Some(fee) = update.protocol_fee_percent {
+            Self::fold_scheduled_fees(env);
+            storage.set(&DataKey::ProtocolFeePercent, &fee);
+        }
+
+        if let Some(treasury) = update.treasury {
+            storage.set(&DataKey::Treasury, &treasury);
+        }
+
+        if let Some(relayer) = update.relayer {
+            storage.set(&DataKey::Relayer, &relayer);
+            Self::move_relayer_role(env, &old_config.relayer, relayer);
+        }
+
+        if let Some(paused) = update.paused {
+            storage.set(&DataKey::Paused, &paused);
+        }
+
+        if let Some(tokens) = update.supported_tokens {
+            for token in old_config.supported_tokens.iter() {
+                storage.remove(&DataKey::TokenSupported(token));
+            }
+            for token in tokens.iter() {
+                storage.set(&DataKey::TokenSupported(token), &true);
+            }
+            storage.set(&DataKey::SupportedTokens, &tokens);
+        }
+
+        let new_config = Self::get_config(env.clone());
+        ConfigUpdatedEvent {
+            version: EVENT_VERSION,
+            old_config,
+            new_config,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Rotate Relayer
+    ///
+    /// ## Description:
+    /// Makes `new_relayer` the primary relayer while the previous key stays accepted
+    /// for `grace_ledgers` more ledgers, so in-flight settlements and refunds land.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the rotation
+    ///
+    /// ## Validation:
+    /// - `new_relayer` must differ from the current relayer
+    /// - Fails with `RotationInProgress` while a previous key is still in its grace period
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `ProtocolAddressUpdatedEvent` under `("ProtocolAddressUpdated", Aggregator)`
+    /// - `RelayerRotatedEvent` under `("RelayerRotated", previous)`
+    pub fn rotate_relayer(
+        env: Env,
+        new_relayer: Address,
+        grace_ledgers: u32,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_relayer_rotation(&env, new_relayer, grace_ledgers)
+    }
+
+    /// # Internal: Apply Relayer Rotation
+    fn apply_relayer_rotation(
+        env: &Env,
+        new_relayer: Address,
+        grace_ledgers: u32,
+    ) -> Result<(), ContractError> {
+        if Self::get_retiring_relayer(env.clone()).is_some() {
+            return Err(ContractError::RotationInProgress);
+        }
+
+        let previous: Address = env.storage().persistent().get(&DataKey::Relayer).unwrap();
+        Self::apply_protocol_address(env, ProtocolAddressType::Aggregator, new_relayer.clone())?;
+
+        let expires_at_ledger = env.ledger().sequence().saturating_add(grace_ledgers);
+        env.storage().persistent().set(
+            &DataKey::RetiringRelayer,
+            &RetiringRelayer {
+                relayer: previous.clone(),
+                expires_at_ledger,
+            },
+        );
+        RelayerRotatedEvent {
+            version: EVENT_VERSION,
+            previous,
+            new_relayer,
+            expires_at_ledger,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Set Fee Distribution
+    ///
+    /// ## Description:
+    /// Splits every protocol fee transfer across weighted recipients instead of
+    /// sending it all to the treasury. Pass an empty list to send fees to the treasury again.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the change
+    ///
+    /// ## Validation:
+    /// - Every weight must be positive and the weights must sum to 100,000 (100%)
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    ///
+    /// ## Events:
+    /// - `FeeDistributionUpdatedEvent` under `("FeeDistributionUpdated",)`
+    pub fn set_fee_distribution(
+        env: Env,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+        Self::require_no_timelock(&env)?;
+
+        Self::apply_fee_distribution(&env, recipients)
+    }
+
+    /// # Internal: Validate Fee Distribution
+    fn validate_fee_distribution(recipients: &Vec<FeeRecipient>) -> Result<(), ContractError> {
+        if !recipients.is_empty() {
+            let mut total: i64 = 0;
+            for share in recipients.iter() {
+                if share.weight_bps <= 0 {
+                    return Err(ContractError::InvalidFeeDistribution);
+                }
+                total += share.weight_bps;
+            }
+
+            if total != 100_000 {
+                return Err(ContractError::InvalidFeeDistribution);
+            }
+        }
+        Ok(())
+    }
+
+    /// # Internal: Apply Fee Distribution
+    fn apply_fee_distribution(
+        env: &Env,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<(), ContractError> {
+        Self::validate_fee_distribution(&recipients)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::FeeDistribution, &recipients);
+        FeeDistributionUpdatedEvent {
+            version: EVENT_VERSION,
+            recipients,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Pause Contract Operations
+    ///
+    /// ## Description:
+    /// Emergency function to pause all order creation and settlements.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin, Pauser or Guardian, must authorize the pause
+    ///
+    /// ## Events:
+    /// - `PausedEvent` under `("Paused",)`
+    ///
+    /// ## Note:
+    /// - Prevents new order creation
+    /// - Existing orders can still be settled/refunded
+    /// - Guardians cannot unpause; that needs the admin, a Pauser or governance
+    pub fn pause(env: Env, caller: Address) -> Result<(), ContractError> {
+        if Self::has_role(env.clone(), Role::Guardian, caller.clone()) {
+            caller.require_auth();
+        } else {
+            Self::require_role(&env, &caller, Role::Pauser)?;
+        }
+        Self::set_paused(&env, true);
+        Ok(())
+    }
+
+    /// # Unpause Contract Operations
+    ///
+    /// ## Description:
+    /// Resumes normal contract operations after a pause.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Pauser, must authorize the unpause (Guardians may not)
+    ///
+    /// ## Events:
+    /// - `UnpausedEvent` under `("Unpaused",)`
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Pauser)?;
+        Self::set_paused(&env, false);
+        Ok(())
+    }
+
+    /// # Internal: Set Paused Flag
+    ///
+    /// ## Events:
+    /// - `PausedEvent` or `UnpausedEvent`
+    fn set_paused(env: &Env, paused: bool) {
+        env.storage().persistent().set(&DataKey::Paused, &paused);
+        if paused {
+            PausedEvent {
+                version: EVENT_VERSION,
+            }
+            .publish(env);
+        } else {
+            UnpausedEvent {
+                version: EVENT_VERSION,
+            }
+            .publish(env);
+        }
+    }
+
+    /// # Pause or Resume a Single Operation
+    ///
+    /// ## Description:
+    /// Toggles one LP contract operation independently of the global pause, e.g.
+    /// stop new orders while refunds keep flowing.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Pauser, must authorize the change
+    ///
+    /// ## Events:
+    /// - `OperationPauseUpdatedEvent` under `("OperationPauseUpdated", operation)`
+    pub fn set_operation_paused(
+        env: Env,
+        caller: Address,
+        operation: PauseOperation,
+        paused: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Pauser)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::OperationPaused(operation), &paused);
+        OperationPauseUpdatedEvent {
+            version: EVENT_VERSION,
+            operation,
+            paused,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Pause or Resume a Token
+    ///
+    /// ## Description:
+    /// Freezes every LP contract operation, including transfers, for orders in `token`.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Pauser, must authorize the change
+    ///
+    /// ## Events:
+    /// - `TokenPauseUpdatedEvent` under `("TokenPauseUpdated", token)`
+    pub fn set_token_paused(
+        env: Env,
+        caller: Address,
+        token: Address,
+        paused: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Pauser)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TokenPaused(token.clone()), &paused);
+        TokenPauseUpdatedEvent {
+            version: EVENT_VERSION,
+            token,
+            paused,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Update Denylist
+    ///
+    /// ## Description:
+    /// Adds (`denied = true`) or removes a batch of sanctioned addresses. Denylisted
+    /// senders cannot create orders, and their refunds only go to approved addresses.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Compliance, must authorize the change
+    ///
+    /// ## Events:
+    /// - `DenylistUpdatedEvent` under `("DenylistUpdated", denied)`
+    pub fn update_denylist(
+        env: Env,
+        caller: Address,
+        accounts: Vec<Address>,
+        denied: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Compliance)?;
+        Self::set_address_flags(&env, DataKey::Denylisted, &accounts, denied)?;
+
+        DenylistUpdatedEvent {
+            version: EVENT_VERSION,
+            accounts,
+            denied,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Update Allowlist
+    ///
+    /// ## Description:
+    /// Adds (`allowed = true`) or removes a batch of screened senders. Only consulted
+    /// while allowlist-only mode is enabled.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Compliance, must authorize the change
+    ///
+    /// ## Events:
+    /// - `AllowlistUpdatedEvent` under `("AllowlistUpdated", allowed)`
+    pub fn update_allowlist(
+        env: Env,
+        caller: Address,
+        accounts: Vec<Address>,
+        allowed: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Compliance)?;
+        Self::set_address_flags(&env, DataKey::Allowlisted, &accounts, allowed)?;
+
+        AllowlistUpdatedEvent {
+            version: EVENT_VERSION,
+            accounts,
+            allowed,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Update Approved Refund Addresses
+    ///
+    /// ## Description:
+    /// Adds (`approved = true`) or removes a batch of addresses that may receive
+    /// refunds of orders created by denylisted senders.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Compliance, must authorize the change
+    ///
+    /// ## Events:
+    /// - `ApprovedRefundAddressesUpdatedEvent` under `("ApprovedRefundAddressesUpdated", approved)`
+    pub fn update_approved_refund_addresses(
+        env: Env,
+        caller: Address,
+        accounts: Vec<Address>,
+        approved: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Compliance)?;
+        Self::set_address_flags(&env, DataKey::ApprovedRefundAddress, &accounts, approved)?;
+
+        ApprovedRefundAddressesUpdatedEvent {
+            version: EVENT_VERSION,
+            accounts,
+            approved,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Set Allowlist-Only Mode
+    ///
+    /// ## Description:
+    /// When enabled, only allowlisted senders may create orders.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Compliance, must authorize the change
+    ///
+    /// ## Events:
+    /// - `AllowlistOnlyUpdatedEvent` under `("AllowlistOnlyUpdated",)`
+    pub fn set_allowlist_only(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&env, &caller, Role::Compliance)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::AllowlistOnly, &enabled);
+        AllowlistOnlyUpdatedEvent {
+            version: EVENT_VERSION,
+            enabled,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Internal: Set Address Flags
+    ///
+    /// ## Description:
+    /// Sets or clears the compliance flag stored under `key(account)` for each account.
+    fn set_address_flags(
+        env: &Env,
+        key: fn(Address) -> DataKey,
+        accounts: &Vec<Address>,
+        flag: bool,
+    ) -> Result<(), ContractError> {
+        if accounts.is_empty() {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        for account in accounts.iter() {
+            if flag {
+                env.storage().persistent().set(&key(account), &true);
+            } else {
+                env.storage().persistent().remove(&key(account));
+            }
+        }
+        Ok(())
+    }
+
+    /// # Set Maximum Order Duration
+    ///
+    /// ## Description:
+    /// Caps how far in the future an order's `expires_at` may be, in seconds from creation.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the change
+    ///
+    /// ## Validation:
+    /// - `max_order_duration` must be positive and at most `MAX_ORDER_DURATION`
+    ///
+    /// ## Events:
+    /// - `MaxOrderDurationUpdatedEvent` under `("MaxOrderDurationUpdated",)`
+    pub fn set_max_order_duration(env: Env, max_order_duration: u64) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+
+        Self::apply_max_order_duration(&env, max_order_duration)
+    }
+
+    /// # Internal: Apply Maximum Order Duration
+    fn apply_max_order_duration(env: &Env, max_order_duration: u64) -> Result<(), ContractError> {
+        if max_order_duration == 0 || max_order_duration > MAX_ORDER_DURATION {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MaxOrderDuration, &max_order_duration);
+        MaxOrderDurationUpdatedEvent {
+            version: EVENT_VERSION,
+            max_order_duration,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Add Supported Token
+    ///
+    /// ## Description:
+    /// Registers a SEP-41 token that orders may be created in.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the change
+    ///
+    /// ## Validation:
+    /// - Token must not already be supported
+    ///
+    /// ## Events:
+    /// - `TokenSupportUpdatedEvent` under `("TokenSupportUpdated", token)`
+    pub fn add_supported_token(env: Env, token: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+
+        Self::apply_add_supported_token(&env, token)
+    }
+
+    /// # Internal: Apply Supported Token Addition
+    fn apply_add_supported_token(env: &Env, token: Address) -> Result<(), ContractError> {
+        if Self::is_token_supported(env.clone(), token.clone()) {
+            return Err(ContractError::TokenAlreadySupported);
+        }
+
+        let mut tokens = Self::get_supported_tokens(env.clone());
+        tokens.push_back(token.clone());
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TokenSupported(token.clone()), &true);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SupportedTokens, &tokens);
+
+        TokenSupportUpdatedEvent {
+            version: EVENT_VERSION,
+            token,
+            supported: true,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Remove Supported Token
+    ///
+    /// ## Description:
+    /// Stops new orders from being created in the given token.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the change
+    ///
+    /// ## Validation:
+    /// - Token must currently be supported
+    ///
+    /// ## Events:
+    /// - `TokenSupportUpdatedEvent` under `("TokenSupportUpdated", token)`
+    ///
+    /// ## Note:
+    /// - Existing orders in the token can still be settled/refunded
+    pub fn remove_supported_token(env: Env, token: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+
+        Self::apply_remove_supported_token(&env, token)
+    }
+
+    /// # Internal: Apply Supported Token Removal
+    fn apply_remove_supported_token(env: &Env, token: Address) -> Result<(), ContractError> {
+        if !Self::is_token_supported(env.clone(), token.clone()) {
+            return Err(ContractError::TokenNotSupported);
+        }
+
+        let mut tokens = Self::get_supported_tokens(env.clone());
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::TokenSupported(token.clone()));
+        env.storage()
+            .persistent()
+            .set(&DataKey::SupportedTokens, &tokens);
+
+        TokenSupportUpdatedEvent {
+            version: EVENT_VERSION,
+            token,
+            supported: false,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Propose New Admin
+    ///
+    /// ## Description:
+    /// Starts a two-step admin transfer. The proposed address only becomes admin
+    /// once it calls `accept_admin`.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the proposal
+    ///
+    /// ## Validation:
+    /// - New admin must differ from the current admin
+    ///
+    /// ## Events:
+    /// - `AdminTransferProposedEvent` under `("AdminTransferProposed", admin)`
+    ///
+    /// ## Note:
+    /// - A new proposal replaces any outstanding one
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        let admin = Self::require_admin(&env)?;
+
+        if new_admin == admin {
+            return Err(ContractError::AddressAlreadySet);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        AdminTransferProposedEvent {
+            version: EVENT_VERSION,
+            admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Accept Admin Role
+    ///
+    /// ## Description:
+    /// Completes a two-step admin transfer started by `propose_admin`.
+    ///
+    /// ## Authorization:
+    /// - Pending admin: Must authorize the acceptance
+    ///
+    /// ## Events:
+    /// - `AdminTransferredEvent` under `("AdminTransferred", previous_admin)`
+    pub fn accept_admin(env: Env) -> Result<(), ContractError> {
+        let pending_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(ContractError::NoPendingAdmin)?;
+        pending_admin.require_auth();
+
+        let previous_admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Admin, &pending_admin);
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
+        AdminTransferredEvent {
+            version: EVENT_VERSION,
+            previous_admin,
+            new_admin: pending_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Cancel Admin Transfer
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the cancellation
+    ///
+    /// ## Events:
+    /// - `AdminTransferCancelledEvent` under `("AdminTransferCancelled", admin)`
+    pub fn cancel_admin_transfer(env: Env) -> Result<(), ContractError> {
+        let admin = Self::require_admin(&env)?;
+
+        let pending_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(ContractError::NoPendingAdmin)?;
+
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
+        AdminTransferCancelledEvent {
+            version: EVENT_VERSION,
+            admin,
+            pending_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Grant Role
+    ///
+    /// ## Description:
+    /// Adds `account` to the holders of `role`. A role may have any number of holders.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the grant
+    ///
+    /// ## Events:
+    /// - `RoleGrantedEvent` under `("RoleGranted", role)`
+    pub fn grant_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+
+        Self::apply_grant_role(&env, role, account)
+    }
+
+    /// # Internal: Apply Role Grant
+    fn apply_grant_role(env: &Env, role: Role, account: Address) -> Result<(), ContractError> {
+        let mut members = Self::get_role_members(env.clone(), role);
+        if members.contains(&account) {
+            return Err(ContractError::RoleAlreadyGranted);
+        }
+
+        members.push_back(account.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::RoleMembers(role), &members);
+        RoleGrantedEvent {
+            version: EVENT_VERSION,
+            role,
+            account,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Revoke Role
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the revocation
+    ///
+    /// ## Validation:
+    /// - The primary relayer keeps the Relayer role; replace it with
+    ///   `update_protocol_address` or `rotate_relayer` instead (`PrimaryRelayer`)
+    ///
+    /// ## Events:
+    /// - `RoleRevokedEvent` under `("RoleRevoked", role)`
+    pub fn revoke_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+
+        Self::apply_revoke_role(&env, role, account)
+    }
+
+    /// # Internal: Apply Role Revocation
+    fn apply_revoke_role(env: &Env, role: Role, account: Address) -> Result<(), ContractError> {
+        Self::remove_role_member(env, role, &account)?;
+        RoleRevokedEvent {
+            version: EVENT_VERSION,
+            role,
+            account,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Renounce Role
+    ///
+    /// ## Description:
+    /// Lets a role holder give up its own role, e.g. when a key is retired.
+    ///
+    /// ## Authorization:
+    /// - `account`: Must authorize the renunciation
+    ///
+    /// ## Validation:
+    /// - The primary relayer cannot renounce the Relayer role (`PrimaryRelayer`)
+    ///
+    /// ## Events:
+    /// - `RoleRenouncedEvent` under `("RoleRenounced", role)`
+    pub fn renounce_role(env: Env, role: Role, account: Address) -> Result<(), ContractError> {
+        account.require_auth();
+
+        Self::remove_role_member(&env, role, &account)?;
+        RoleRenouncedEvent {
+            version: EVENT_VERSION,
+            role,
+            account,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Internal: Remove Role Member
+    ///
+    /// ## Description:
+    /// Keeps `get_relayer_address` authorized by refusing to drop the primary relayer's role.
+    fn remove_role_member(env: &Env, role: Role, account: &Address) -> Result<(), ContractError> {
+        if role == Role::Relayer && *account == Self::get_relayer_address(env.clone()) {
+            return Err(ContractError::PrimaryRelayer);
+        }
+
+        let mut members = Self::get_role_members(env.clone(), role);
+        let index = members
+            .first_index_of(account)
+            .ok_or(ContractError::RoleNotGranted)?;
+
+        members.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::RoleMembers(role), &members);
+        Ok(())
+    }
+
+    // ========== TIMELOCK GOVERNANCE ==========
+
+    /// # Internal: Require No Timelock
+    ///
+    /// ## Description:
+    /// Blocks immediate sensitive changes once a timelock delay has been configured.
+    fn require_no_timelock(env: &Env) -> Result<(), ContractError> {
+        if Self::get_timelock_delay(env.clone()) > 0 {
+            return Err(ContractError::TimelockRequired);
+        }
+        Ok(())
+    }
+
+    /// # Queue Governance Action
+    ///
+    /// ## Description:
+    /// Schedules a sensitive settings change to become executable after the timelock delay.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the queueing
+    ///
+    /// ## Validation:
+    /// - Fee actions must be between 0 and 100,000 basis points
+    /// - Timelock delays must not exceed `MAX_TIMELOCK_DELAY`
+    /// - Signer set changes can only go through signer approval
+    /// - Fails with `SignerApprovalRequired` once signers are configured; use `propose`
+    ///
+    /// ## Events:
+    /// - `ActionQueuedEvent` under `("ActionQueued", id)`
+    ///
+    /// ## Returns:
+    /// - Identifier of the queued action
+    pub fn queue_action(env: Env, action: GovernanceAction) -> Result<u64, ContractError> {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if let GovernanceAction::UpdateSignerSet(..) = action {
+            return Err(ContractError::Unauthorized);
+        }
+        Self::require_no_signers(&env)?;
+
+        Self::enqueue_action(&env, action)
+    }
+
+    /// # Internal: Enqueue Action
+    ///
+    /// ## Description:
+    /// Stores an action behind the current timelock delay.
+    fn enqueue_action(env: &Env, action: GovernanceAction) -> Result<u64, ContractError> {
+        match &action {
+            GovernanceAction::UpdateProtocolFee(fee)
+            | GovernanceAction::SetTokenFee(_, fee)
+            | GovernanceAction::SetLpFeeOverride(_, fee, _)
+            | GovernanceAction::SetCancellationFee(fee)
+                if !(0..=100_000).contains(fee) =>
+            {
+                return Err(ContractError::InvalidFeePercent);
+            }
+            GovernanceAction::UpdateTimelockDelay(delay) if *delay > MAX_TIMELOCK_DELAY => {
+                return Err(ContractError::InvalidParameter);
+            }
+            GovernanceAction::SetTokenFeeBounds(_, min_fee, max_fee)
+                if *min_fee < 0 || min_fee > max_fee =>
+            {
+                return Err(ContractError::InvalidParameter);
+            }
+            GovernanceAction::UpdateConfig(update) => Self::validate_config_update(update)?,
+            GovernanceAction::SetFeeTiers(tiers) => Self::validate_fee_tiers(tiers)?,
+            GovernanceAction::UpdateFeeDistribution(recipients) => {
+                Self::validate_fee_distribution(recipients)?
+            }
+            _ => {}
+        }
+
+        let id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextActionId)
+            .unwrap_or(0);
+        let eta = env.ledger().timestamp() + Self::get_timelock_delay(env.clone());
+
+        let queued = QueuedAction {
+            id,
+            action: action.clone(),
+            eta,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::QueuedAction(id), &queued);
+        env.storage()
+            .persistent()
+            .set(&DataKey::NextActionId, &(id + 1));
+
+        ActionQueuedEvent {
+            version: EVENT_VERSION,
+            id,
+            action,
+            eta,
+        }
+        .publish(env);
+        Ok(id)
+    }
+
+    /// # Execute Governance Action
+    ///
+    /// ## Description:
+    /// Applies a queued action once its timelock has elapsed. Permissionless, so
+    /// integrators can execute an announced change themselves.
+    ///
+    /// ## Validation:
+    /// - Action must be queued
+    /// - Current ledger timestamp must be at or past the action's eta
+    ///
+    /// ## Events:
+    /// - `ActionExecutedEvent` under `("ActionExecuted", id)`
+    pub fn execute_action(env: Env, id: u64) -> Result<(), ContractError> {
+        let queued: QueuedAction = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QueuedAction(id))
+            .ok_or(ContractError::ActionNotFound)?;
+
+        if env.ledger().timestamp() < queued.eta {
+            return Err(ContractError::TimelockNotElapsed);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::QueuedAction(id));
+
+        Self::apply_action(&env, queued.action.clone())?;
+
+        ActionExecutedEvent {
+            version: EVENT_VERSION,
+            id,
+            action: queued.action,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Internal: Apply Governance Action
+    ///
+    /// ## Description:
+    /// Performs the settings change described by `action`. Callers are responsible
+    /// for authorization and timelock checks.
+    fn apply_action(env: &Env, action: GovernanceAction) -> Result<(), ContractError> {
+        match action {
+            GovernanceAction::UpdateProtocolFee(fee) => Self::apply_protocol_fee(env, fee),
+            GovernanceAction::UpdateProtocolAddress(what, value) => {
+                Self::apply_protocol_address(env, what, value)
+            }
+            GovernanceAction::UpgradeLpManager(new_wasm_hash) => {
+                env.deployer().update_current_contract_wasm(new_wasm_hash);
+                Ok(())
+            }
+            GovernanceAction::UpdateTimelockDelay(delay) => {
+                if delay > MAX_TIMELOCK_DELAY {
+                    return Err(ContractError::InvalidParameter);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::TimelockDelay, &delay);
+                Ok(())
+            }
+            GovernanceAction::Pause => {
+                Self::set_paused(env, true);
+                Ok(())
+            }
+            GovernanceAction::Unpause => {
+                Self::set_paused(env, false);
+                Ok(())
+            }
+            GovernanceAction::UpdateFeeDistribution(recipients) => {
+                Self::apply_fee_distribution(env, recipients)
+            }
+            GovernanceAction::RotateRelayer(new_relayer, grace_ledgers) => {
+                Self::apply_relayer_rotation(env, new_relayer, grace_ledgers)
+            }
+            GovernanceAction::SetTokenFee(token, fee) => Self::apply_token_fee(env, token, fee),
+            GovernanceAction::RemoveTokenFee(token) => Self::apply_remove_token_fee(env, token),
+            GovernanceAction::UpdateSignerSet(signers, threshold) => {
+                Self::store_signer_set(env, signers, threshold)
+            }
+            GovernanceAction::GrantRole(role, account) => {
+                Self::apply_grant_role(env, role, account)
+            }
+            GovernanceAction::RevokeRole(role, account) => {
+                Self::apply_revoke_role(env, role, account)
+            }
+            GovernanceAction::AddSupportedToken(token) => {
+                Self::apply_add_supported_token(env, token)
+            }
+            GovernanceAction::RemoveSupportedToken(token) => {
+                Self::apply_remove_supported_token(env, token)
+            }
+            GovernanceAction::SetMaxOrderDuration(max_order_duration) => {
+                Self::apply_max_order_duration(env, max_order_duration)
+            }
+            GovernanceAction::CancelAction(id) => Self::apply_cancel_action(env, id),
+            GovernanceAction::SetFeeTiers(tiers) => Self::apply_fee_tiers(env, tiers),
+            GovernanceAction::SetTokenFeeBounds(token, min_fee, max_fee) => {
+                Self::apply_token_fee_bounds(env, token, min_fee, max_fee)
+            }
+            GovernanceAction::RemoveTokenFeeBounds(token) => {
+                Self::apply_remove_token_fee_bounds(env, token)
+            }
+            GovernanceAction::SetLpFeeOverride(liquidity_provider, fee_percent, expires_at) => {
+                Self::apply_lp_fee_override(env, liquidity_provider, fee_percent, expires_at)
+            }
+            GovernanceAction::RemoveLpFeeOverride(liquidity_provider) => {
+                Self::apply_remove_lp_fee_override(env, liquidity_provider)
+            }
+            GovernanceAction::UpdateConfig(update) => Self::apply_config_update(env, update),
+            GovernanceAction::SetCancellationFee(fee_percent) => {
+                Self::apply_cancellation_fee(env, fee_percent)
+            }
+        }
+    }
+
+    /// # Internal: Is Timelocked
+    ///
+    /// ## Returns:
+    /// - `true` for actions that must wait out the timelock delay when one is configured
+    fn is_timelocked(action: &GovernanceAction) -> bool {
+        matches!(
+            action,
+            GovernanceAction::UpdateProtocolFee(_)
+                | GovernanceAction::UpdateProtocolAddress(..)
+                | GovernanceAction::UpgradeLpManager(_)
+                | GovernanceAction::UpdateTimelockDelay(_)
+                | GovernanceAction::UpdateFeeDistribution(_)
+                | GovernanceAction::RotateRelayer(..)
+                | GovernanceAction::SetTokenFee(..)
+                | GovernanceAction::RemoveTokenFee(_)
+                | GovernanceAction::SetFeeTiers(_)
+                | GovernanceAction::SetTokenFeeBounds(..)
+                | GovernanceAction::RemoveTokenFeeBounds(_)
+                | GovernanceAction::SetLpFeeOverride(..)
+                | GovernanceAction::RemoveLpFeeOverride(_)
+                | GovernanceAction::UpdateConfig(_)
+                | GovernanceAction::SetCancellationFee(_)
+        )
+    }
+
+    /// # Cancel Governance Action
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the cancellation
+    ///
+    /// ## Note:
+    /// - Once signers are configured only a `CancelAction` proposal can cancel
+    ///
+    /// ## Events:
+    /// - `ActionCancelledEvent` under `("ActionCancelled", id)`
+    pub fn cancel_action(env: Env, id: u64) -> Result<(), ContractError> {
+        Self::require_admin(&env)?;
+
+        Self::apply_cancel_action(&env, id)
+    }
+
+    /// # Internal: Apply Action Cancellation
+    fn apply_cancel_action(env: &Env, id: u64) -> Result<(), ContractError> {
+        let queued: QueuedAction = env
+            .storage()
+            .persistent()
+            .get(&DataKey::QueuedAction(id))
+            .ok_or(ContractError::ActionNotFound)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::QueuedAction(id));
+
+        ActionCancelledEvent {
+            version: EVENT_VERSION,
+            id,
+            action: queued.action,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    // ========== MULTISIG SIGNERS ==========
+
+    /// # Register Signer Set
+    ///
+    /// ## Description:
+    /// One-time registration of the M-of-N signer set. Later changes must be
+    /// approved by the signers themselves through an `UpdateSignerSet` proposal.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the registration
+    ///
+    /// ## Validation:
+    /// - Signer set must not already be configured
+    /// - Signers must be unique and 1 ≤ threshold ≤ number of signers
+    ///
+    /// ## Events:
+    /// - `SignerSetUpdatedEvent` under `("SignerSetUpdated",)`
+    pub fn set_signers(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if env.storage().persistent().has(&DataKey::Signers) {
+            return Err(ContractError::SignerSetAlreadyConfigured);
+        }
+
+        Self::store_signer_set(&env, signers, threshold)
+    }
+
+    /// # Internal: Store Signer Set
+    fn store_signer_set(
+        env: &Env,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) -> Result<(), ContractError> {
+        if threshold == 0 || threshold > signers.len() {
+            return Err(ContractError::InvalidThreshold);
+        }
+
+        for (index, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(index as u32) {
+                return Err(ContractError::InvalidParameter);
+            }
+        }
+
+        env.storage().persistent().set(&DataKey::Signers, &signers);
+        env.storage()
+            .persistent()
+            .set(&DataKey::SignerThreshold, &threshold);
+        SignerSetUpdatedEvent {
+            version: EVENT_VERSION,
+            signers,
+            threshold,
+        }
+        .publish(env);
+        Ok(())
+    }
+
+    /// # Internal: Require Signer
+    fn require_signer(env: &Env, signer: &Address) -> Result<(), ContractError> {
+        signer.require_auth();
+
+        if !Self::get_signers(env.clone()).contains(signer) {
+            return Err(ContractError::NotSigner);
+        }
+        Ok(())
+    }
+
+    /// # Propose Signer Action
+    ///
+    /// ## Description:
+    /// Opens a proposal for a governance action. The proposer's approval is counted.
+    /// The proposal expires `PROPOSAL_LIFETIME` seconds after creation.
+    ///
+    /// ## Authorization:
+    /// - `signer`: Must be a registered signer and authorize the proposal
+    ///
+    /// ## Events:
+    /// - `ProposalCreatedEvent` under `("ProposalCreated", id)`
+    ///
+    /// ## Returns:
+    /// - Identifier of the new proposal
+    pub fn propose(
+        env: Env,
+        signer: Address,
+        action: GovernanceAction,
+    ) -> Result<u64, ContractError> {
+        Self::require_signer(&env, &signer)?;
+
+        let id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0);
+
+        let mut approvals = Vec::new(&env);
+        approvals.push_back(signer.clone());
+
+        let proposal = MultisigProposal {
+            id,
+            action: action.clone(),
+            proposer: signer.clone(),
+            approvals,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(id), &proposal);
+        env.storage().persistent().set(
+            &DataKey::ProposalExpiry(id),
+            &(env.ledger().timestamp() + PROPOSAL_LIFETIME),
+        );
+        env.storage()
+            .persistent()
+            .set(&DataKey::NextProposalId, &(id + 1));
+
+        ProposalCreatedEvent {
+            version: EVENT_VERSION,
+            id,
+            signer,
+            action,
+        }
+        .publish(&env);
+        Ok(id)
+    }
+
+    /// # Approve Signer Action
+    ///
+    /// ## Authorization:
+    /// - `signer`: Must be a registered signer and authorize the approval
+    ///
+    /// ## Validation:
+    /// - Proposal must exist, not have expired and not already be approved by `signer`
+    ///
+    /// ## Events:
+    /// - `ProposalApprovedEvent` under `("ProposalApproved", id)`
+    pub fn approve_proposal(env: Env, signer: Address, id: u64) -> Result<(), ContractError> {
+        Self::require_signer(&env, &signer)?;
+
+        let mut proposal: MultisigProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(id))
+            .ok_or(ContractError::ProposalNotFound)?;
+        Self::require_proposal_open(&env, id)?;
+
+        if proposal.approvals.contains(&signer) {
+            return Err(ContractError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(signer.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(id), &proposal);
+
+        ProposalApprovedEvent {
+            version: EVENT_VERSION,
+            id,
+            signer,
+            approvals: proposal.approvals.len(),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Execute Signer Action
+    ///
+    /// ## Description:
+    /// Applies a proposal once enough current signers have approved it. Permissionless.
+    ///
+    /// ## Validation:
+    /// - Proposal must not have expired
+    /// - Approvals from addresses still in the signer set must reach the threshold
+    ///
+    /// ## Events:
+    /// - `ProposalExecutedEvent` under `("ProposalExecuted", id)`
+    ///
+    /// ## Note:
+    /// - While a timelock delay is configured, timelocked actions are placed in the
+    ///   timelock queue instead of applied immediately
+    pub fn execute_proposal(env: Env, id: u64) -> Result<(), ContractError> {
+        let proposal: MultisigProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(id))
+            .ok_or(ContractError::ProposalNotFound)?;
+        Self::require_proposal_open(&env, id)?;
+
+        let signers = Self::get_signers(env.clone());
+        let threshold = Self::get_signer_threshold(env.clone());
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|approver| signers.contains(approver))
+            .count() as u32;
+
+        if threshold == 0 || approvals < threshold {
+            return Err(ContractError::ThresholdNotReached);
+        }
+
+        env.storage().persistent().remove(&DataKey::Proposal(id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ProposalExpiry(id));
+
+        if Self::is_timelocked(&proposal.action) && Self::get_timelock_delay(env.clone()) > 0 {
+            Self::enqueue_action(&env, proposal.action.clone())?;
+        } else {
+            Self::apply_action(&env, proposal.action.clone())?;
+        }
+
+        ProposalExecutedEvent {
+            version: EVENT_VERSION,
+            id,
+            action: proposal.action,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Cancel Signer Action
+    ///
+    /// ## Description:
+    /// Withdraws an open proposal so its approvals can never be used.
+    ///
+    /// ## Authorization:
+    /// - `signer`: Must be the proposal's proposer, still a registered signer, and authorize the cancellation
+    ///
+    /// ## Events:
+    /// - `ProposalCancelledEvent` under `("ProposalCancelled", id)`
+    pub fn cancel_proposal(env: Env, signer: Address, id: u64) -> Result<(), ContractError> {
+        Self::require_signer(&env, &signer)?;
+
+        let proposal: MultisigProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(id))
+            .ok_or(ContractError::ProposalNotFound)?;
+
+        if proposal.proposer != signer {
+            return Err(ContractError::Unauthorized);
+        }
+
+        env.storage().persistent().remove(&DataKey::Proposal(id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ProposalExpiry(id));
+        ProposalCancelledEvent {
+            version: EVENT_VERSION,
+            id,
+            signer,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Internal: Require Proposal Open
+    ///
+    /// ## Note:
+    /// - Proposals created before expiry was introduced have no expiry
+    fn require_proposal_open(env: &Env, id: u64) -> Result<(), ContractError> {
+        let expires_at: Option<u64> = env.storage().persistent().get(&DataKey::ProposalExpiry(id));
+        if expires_at.is_some_and(|expires_at| env.ledger().timestamp() >= expires_at) {
+            return Err(ContractError::ProposalExpired);
+        }
+        Ok(())
+    }
+
+    // ========== VIEW FUNCTIONS ==========
+
+    /// # Get Signers
+    ///
+    /// ## Returns:
+    /// - Registered signer addresses (empty if signer mode is not configured)
+    pub fn get_signers(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Signers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// # Get Signer Threshold
+    ///
+    /// ## Returns:
+    /// - Number of approvals required to execute a proposal (0 if not configured)
+    pub fn get_signer_threshold(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignerThreshold)
+            .unwrap_or(0)
+    }
+
+    /// # Get Proposal
+    ///
+    /// ## Returns:
+    /// - The pending proposal and its approvals, if it has not been executed
+    pub fn get_proposal(env: Env, id: u64) -> Option<MultisigProposal> {
+        env.storage().persistent().get(&DataKey::Proposal(id))
+    }
+
+    /// # Get Proposal Expiry
+    ///
+    /// ## Returns:
+    /// - Ledger timestamp from which the proposal can no longer be approved or executed
+    pub fn get_proposal_expiry(env: Env, id: u64) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::ProposalExpiry(id))
+    }
+
+    /// # Get Timelock Delay
+    ///
+    /// ## Returns:
+    /// - Delay in seconds between queueing and execution (0 = timelock disabled)
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TimelockDelay)
+            .unwrap_or(0)
+    }
+
+    /// # Get Queued Action
+    ///
+    /// ## Returns:
+    /// - The queued action and its eta, if it has not been executed or cancelled
+    pub fn get_queued_action(env: Env, id: u64) -> Option<QueuedAction> {
+        env.storage().persistent().get(&DataKey::QueuedAction(id))
+    }
+
+    /// # Check Role Membership
+    ///
+    /// ## Returns:
+    /// - `true` if `account` has been granted `role`
+    ///
+    /// ## Note:
+    /// - The admin is not reported here unless explicitly granted the role
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        Self::get_role_members(env, role).contains(&account)
+    }
+
+    /// # Get Role Members
+    ///
+    /// ## Returns:
+    /// - All addresses currently holding `role`
+    pub fn get_role_members(env: Env, role: Role) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RoleMembers(role))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// # Get Admin Address
+    ///
+    /// ## Returns:
+    /// - Current admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().persistent().get(&DataKey::Admin).unwrap()
+    }
+
+    /// # Get Pending Admin Address
+    ///
+    /// ## Returns:
+    /// - Address proposed via `propose_admin`, if a transfer is outstanding
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::PendingAdmin)
+    }
+
+    /// # Get Current Fee Details
+    ///
+    /// ## Description:
+    /// Returns the fee in force at the current ledger timestamp, taking scheduled
+    /// fee changes into account.
+    ///
+    /// ## Returns:
+    /// - Tuple of (protocol_fee_percent, max_bps)
+    /// - Default: (1000, 100000) = 1% fee
+    pub fn get_fee_details(env: Env) -> (i64, i64) {
+        let mut protocol_fee_percent: i64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ProtocolFeePercent)
+            .unwrap_or(0);
+        let schedule: Vec<ScheduledFee> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScheduledFees)
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+        for scheduled in schedule.iter() {
+            if scheduled.effective_at > now {
+                break;
+            }
+            protocol_fee_percent = scheduled.fee_percent;
+        }
+        let max_bps: i64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MaxBps)
+            .unwrap_or(100_000);
+        (protocol_fee_percent, max_bps)
+    }
+
+    /// # Get Protocol Config
+    ///
+    /// ## Returns:
+    /// - Current fee, max BPS, treasury, primary relayer, global pause flag and supported tokens
+    pub fn get_config(env: Env) -> ProtocolConfig {
+        let (protocol_fee_percent, max_bps) = Self::get_fee_details(env.clone());
+
+        ProtocolConfig {
+            protocol_fee_percent,
+            max_bps,
+            treasury: Self::get_treasury_address(env.clone()),
+            relayer: Self::get_relayer_address(env.clone()),
+            paused: Self::is_paused(env.clone()),
+            supported_tokens: Self::get_supported_tokens(env),
+        }
+    }
+
+    /// # Get Fee Details for an Order
+    ///
+    /// ## Description:
+    /// Resolves the fee for an order of `amount` in `token`, in order of precedence:
+    /// 1. Per-token fee override
+    /// 2. Volume tier containing `amount`
+    /// 3. Global protocol fee (including scheduled changes)
+    ///
+    /// ## Returns:
+    /// - Tuple of (protocol_fee_percent, max_bps)
+    pub fn get_order_fee_details(env: Env, token: Address, amount: i128) -> (i64, i64) {
+        let (global_fee_percent, max_bps) = Self::get_fee_details(env.clone());
+
+        if let Some(token_fee_percent) = env
+            .storage()
+            .persistent()
+            .get::<_, i64>(&DataKey::TokenFeePercent(token))
+        {
+            return (token_fee_percent, max_bps);
+        }
+
+        for tier in Self::get_fee_tiers(env.clone()).iter() {
+            if amount >= tier.min_amount && amount < tier.max_amount {
+                return (tier.fee_percent, max_bps);
+            }
+        }
+
+        (global_fee_percent, max_bps)
+    }
+
+    /// # Get Liquidity Provider Fee Override
+    ///
+    /// ## Returns:
+    /// - The stored override for `liquidity_provider`, including expired ones
+    pub fn get_lp_fee_override(env: Env, liquidity_provider: Address) -> Option<LpFeeOverride> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LpFeeOverride(liquidity_provider))
+    }
+
+    /// # Get Active Liquidity Provider Fee
+    ///
+    /// ## Returns:
+    /// - The negotiated fee percentage if an unexpired override exists
+    pub fn get_active_lp_fee(env: Env, liquidity_provider: Address) -> Option<i64> {
+        let lp_override = Self::get_lp_fee_override(env.clone(), liquidity_provider)?;
+
+        match lp_override.expires_at {
+            Some(expiry) if env.ledger().timestamp() > expiry => None,
+            _ => Some(lp_override.fee_percent),
+        }
+    }
+
+    /// # Get Token Fee Bounds
+    ///
+    /// ## Returns:
+    /// - The token's absolute fee floor and cap, if configured
+    pub fn get_token_fee_bounds(env: Env, token: Address) -> Option<FeeBounds> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenFeeBounds(token))
+    }
+
+    /// # Get Volume Limit
+    ///
+    /// ## Returns:
+    /// - The volume limit for `token`, or the global limit when `token` is `None`
+    pub fn get_volume_limit(env: Env, token: Option<Address>) -> Option<VolumeLimit> {
+        env.storage().persistent().get(&DataKey::VolumeLimit(token))
+    }
+
+    /// # Get Fee Tiers
+    ///
+    /// ## Returns:
+    /// - Configured volume fee tiers (empty when the flat fee applies)
+    pub fn get_fee_tiers(env: Env) -> Vec<FeeTier> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::FeeTiers)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// # Get Scheduled Fees
+    ///
+    /// ## Returns:
+    /// - Upcoming fee changes that are not yet in force, ordered by effective time
+    pub fn get_scheduled_fees(env: Env) -> Vec<ScheduledFee> {
+        let schedule: Vec<ScheduledFee> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ScheduledFees)
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+
+        let mut upcoming = Vec::new(&env);
+        for scheduled in schedule.iter() {
+            if scheduled.effective_at > now {
+                upcoming.push_back(scheduled);
+            }
+        }
+        upcoming
+    }
+
+    /// # Get Fee Details for a Token
+    ///
+    /// ## Description:
+    /// Returns the token's fee override, falling back to the global protocol fee.
+    ///
+    /// ## Returns:
+    /// - Tuple of (protocol_fee_percent, max_bps)
+    pub fn get_token_fee_details(env: Env, token: Address) -> (i64, i64) {
+        let (global_fee_percent, max_bps) = Self::get_fee_details(env.clone());
+        let protocol_fee_percent: i64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenFeePercent(token))
+            .unwrap_or(global_fee_percent);
+        (protocol_fee_percent, max_bps)
+    }
+
+    /// # Get Cancellation Fee
+    ///
+    /// ## Returns:
+    /// - Fee in basis points charged on sender cancellations, 0 if unset
+    pub fn get_cancellation_fee(env: Env) -> i64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CancellationFeePercent)
+            .unwrap_or(0)
+    }
+
+    /// # Get Treasury Address
+    ///
+    /// ## Returns:
+    /// - Current treasury address for fee collection
+    pub fn get_treasury_address(env: Env) -> Address {
+        env.storage().persistent().get(&DataKey::Treasury).unwrap()
+    }
+
+    /// # Get Fee Distribution
+    ///
+    /// ## Returns:
+    /// - Weighted fee recipients; the treasury at 100% when no distribution is configured
+    pub fn get_fee_distribution(env: Env) -> Vec<FeeRecipient> {
+        let recipients: Vec<FeeRecipient> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::FeeDistribution)
+            .unwrap_or(Vec::new(&env));
+
+        if !recipients.is_empty() {
+            return recipients;
+        }
+
+        let mut treasury_only = Vec::new(&env);
+        treasury_only.push_back(FeeRecipient {
+            recipient: Self::get_treasury_address(env.clone()),
+            weight_bps: 100_000,
+        });
+        treasury_only
+    }
+
+    /// # Get Relayer Address
+    ///
+    /// ## Returns:
+    /// - Primary relayer address; see `get_role_members(Relayer)` for every authorized relayer
+    pub fn get_relayer_address(env: Env) -> Address {
+        env.storage().persistent().get(&DataKey::Relayer).unwrap()
+    }
+
+    /// # Get Retiring Relayer
+    ///
+    /// ## Returns:
+    /// - The previous relayer key from `rotate_relayer`, while its grace period lasts
+    pub fn get_retiring_relayer(env: Env) -> Option<RetiringRelayer> {
+        let retiring: RetiringRelayer =
+            env.storage().persistent().get(&DataKey::RetiringRelayer)?;
+
+        if env.ledger().sequence() > retiring.expires_at_ledger {
+            return None;
+        }
+        Some(retiring)
+    }
+
+    /// # Check if Relayer is Authorized
+    ///
+    /// ## Returns:
+    /// - `true` if `account` holds the Relayer role or is the retiring relayer within
+    ///   its grace period
+    pub fn is_relayer_authorized(env: Env, account: Address) -> bool {
+        if Self::has_role(env.clone(), Role::Relayer, account.clone()) {
+            return true;
+        }
+
+        Self::get_retiring_relayer(env).is_some_and(|retiring| retiring.relayer == account)
+    }
+
+    /// # Check if Contract is Paused
+    ///
+    /// ## Returns:
+    /// - `true` if contract operations are paused
+    /// - `false` if contract is operational
+    pub fn is_paused(env: Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Paused)
+            .unwrap_or(false)
+    }
+
+    /// # Check if an Operation is Paused for a Token
+    ///
+    /// ## Description:
+    /// Combines the global, per-operation and per-token pause flags.
+    ///
+    /// ## Returns:
+    /// - `true` if the token is paused or the operation is paused
+    /// - `true` if the global pause is on and the operation is create, settle or refund
+    pub fn is_operation_paused(env: Env, operation: PauseOperation, token: Address) -> bool {
+        if Self::is_token_paused(env.clone(), token) {
+            return true;
+        }
+
+        if env
+            .storage()
+            .persistent()
+            .get(&DataKey::OperationPaused(operation))
+            .unwrap_or(false)
+        {
+            return true;
+        }
+
+        match operation {
+            PauseOperation::CreateOrder | PauseOperation::Settle | PauseOperation::Refund => {
+                Self::is_paused(env.clone())
+            }
+            PauseOperation::SettlementTransfer | PauseOperation::RefundTransfer => false,
+        }
+    }
+
+    /// # Check if Token is Paused
+    ///
+    /// ## Returns:
+    /// - `true` if every operation on `token` is frozen
+    pub fn is_token_paused(env: Env, token: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenPaused(token))
+            .unwrap_or(false)
+    }
+
+    /// # Get Pause State
+    ///
+    /// ## Returns:
+    /// - The global pause flag and each per-operation flag
+    pub fn get_pause_state(env: Env) -> PauseState {
+        let storage = env.storage().persistent();
+        let operation_paused = |operation: PauseOperation| -> bool {
+            storage
+                .get(&DataKey::OperationPaused(operation))
+                .unwrap_or(false)
+        };
+
+        PauseState {
+            global: Self::is_paused(env.clone()),
+            create_order: operation_paused(PauseOperation::CreateOrder),
+            settle: operation_paused(PauseOperation::Settle),
+            refund: operation_paused(PauseOperation::Refund),
+            settlement_transfer: operation_paused(PauseOperation::SettlementTransfer),
+            refund_transfer: operation_paused(PauseOperation::RefundTransfer),
+        }
+    }
+
+    /// # Check if Token is Supported
+    ///
+    /// ## Description:
+    /// Looks up the token in the admin-managed registry.
+    ///
+    /// ## Returns:
+    /// - `true` if token is supported for orders
+    /// - `false` if token is not supported
+    pub fn is_token_supported(env: Env, token: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenSupported(token))
+            .unwrap_or(false)
+    }
+
+    /// # Check if Address is Denylisted
+    ///
+    /// ## Returns:
+    /// - `true` if `account` is on the compliance denylist
+    pub fn is_denylisted(env: Env, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Denylisted(account))
+            .unwrap_or(false)
+    }
+
+    /// # Check if Address is Allowlisted
+    ///
+    /// ## Returns:
+    /// - `true` if `account` is on the compliance allowlist
+    pub fn is_allowlisted(env: Env, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Allowlisted(account))
+            .unwrap_or(false)
+    }
+
+    /// # Check if Allowlist-Only Mode is Enabled
+    ///
+    /// ## Returns:
+    /// - `true` if only allowlisted senders may create orders
+    pub fn is_allowlist_only(env: Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowlistOnly)
+            .unwrap_or(false)
+    }
+
+    /// # Check if Address is an Approved Refund Address
+    ///
+    /// ## Returns:
+    /// - `true` if `account` may receive refunds for denylisted senders
+    pub fn is_approved_refund_address(env: Env, account: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ApprovedRefundAddress(account))
+            .unwrap_or(false)
+    }
+
+    /// # Check if Sender is Allowed
+    ///
+    /// ## Returns:
+    /// - `false` if `sender` is denylisted, or allowlist-only mode is enabled and
+    ///   `sender` is not allowlisted
+    pub fn is_sender_allowed(env: Env, sender: Address) -> bool {
+        if Self::is_denylisted(env.clone(), sender.clone()) {
+            return false;
+        }
+
+        !Self::is_allowlist_only(env.clone()) || Self::is_allowlisted(env, sender)
+    }
+
+    /// # Check if Refund is Allowed
+    ///
+    /// ## Returns:
+    /// - `true` unless `sender` is denylisted and `refund_address` is not approved
+    pub fn is_refund_allowed(env: Env, sender: Address, refund_address: Address) -> bool {
+        !Self::is_denylisted(env.clone(), sender)
+            || Self::is_approved_refund_address(env, refund_address)
+    }
+
+    /// # Get Maximum Order Duration
+    ///
+    /// ## Returns:
+    /// - Longest allowed order lifetime in seconds, `DEFAULT_MAX_ORDER_DURATION` if unset
+    pub fn get_max_order_duration(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxOrderDuration)
+            .unwrap_or(DEFAULT_MAX_ORDER_DURATION)
+    }
+
+    /// # Get Supported Tokens
+    ///
+    /// ## Returns:
+    /// - All tokens currently accepted for new orders, in registration order
+    pub fn get_supported_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SupportedTokens)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// # Upgrade Settings Manager WASM
+    ///
+    /// ## Description:
+    /// Updates the settings manager contract's WASM code.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Admin or Upgrader, must authorize the upgrade
+    ///
+    /// ## Note:
+    /// - Maintains all existing settings and state
+    /// - Fails with `TimelockRequired` once a timelock delay is configured; use `queue_action`
+    pub fn upgrade_lp_manager(
+        e: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        Self::require_role(&e, &caller, Role::Upgrader)?;
+        Self::require_no_timelock(&e)?;
+
         e.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
     }
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -15,14 +15,229 @@ pub enum ProtocolAddressType {
     Aggregator,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    FeeManager,
+    Pauser,
+    Upgrader,
+    Relayer,
+    Compliance,
+    /// Incident responder that may `pause` but never unpause
+    Guardian,
+}
+
+/// Protocol fee change announced ahead of time
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledFee {
+    pub fee_percent: i64,
+    /// Ledger timestamp from which the fee applies
+    pub effective_at: u64,
+}
+
+/// Volume tier of the protocol fee schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    /// Inclusive lower bound of the order amount
+    pub min_amount: i128,
+    /// Exclusive upper bound of the order amount
+    pub max_amount: i128,
+    pub fee_percent: i64,
+}
+
+/// Absolute protocol fee floor and cap for a token, in token units
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeBounds {
+    pub min_fee: i128,
+    pub max_fee: i128,
+}
+
+/// Negotiated protocol fee for a liquidity provider
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpFeeOverride {
+    pub fee_percent: i64,
+    /// Ledger timestamp after which the override lapses; `None` never expires
+    pub expires_at: Option<u64>,
+}
+
+/// Share of every protocol fee paid to one recipient
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeRecipient {
+    pub recipient: Address,
+    /// Share in basis points of max BPS (100,000 = 100%)
+    pub weight_bps: i64,
+}
+
+/// Cap on order volume created within a rolling time window
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VolumeLimit {
+    /// Maximum order volume, in token units, accepted per window
+    pub max_volume: i128,
+    /// Length of the trailing window in seconds
+    pub window_seconds: u64,
+    /// Window volume at which the LP contract trips its circuit breaker
+    pub auto_pause_at: Option<i128>,
+}
+
+/// Previous relayer key still accepted during a rotation grace period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetiringRelayer {
+    pub relayer: Address,
+    /// Last ledger sequence at which the key is accepted
+    pub expires_at_ledger: u32,
+}
+
+/// Snapshot of the core protocol settings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfig {
+    pub protocol_fee_percent: i64,
+    pub max_bps: i64,
+    pub treasury: Address,
+    pub relayer: Address,
+    pub paused: bool,
+    pub supported_tokens: Vec<Address>,
+}
+
+/// Partial update of `ProtocolConfig`; `None` fields are left unchanged
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProtocolConfigUpdate {
+    pub protocol_fee_percent: Option<i64>,
+    pub treasury: Option<Address>,
+    pub relayer: Option<Address>,
+    pub paused: Option<bool>,
+    /// Replaces the whole supported token set
+    pub supported_tokens: Option<Vec<Address>>,
+}
+
+/// Individually pausable LP contract operation
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseOperation {
+    CreateOrder,
+    Settle,
+    Refund,
+    SettlementTransfer,
+    RefundTransfer,
+}
+
+/// Snapshot of every protocol-wide pause switch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseState {
+    /// Global pause; blocks order creation, settlement and refund
+    pub global: bool,
+    pub create_order: bool,
+    pub settle: bool,
+    pub refund: bool,
+    pub settlement_transfer: bool,
+    pub refund_transfer: bool,
+}
+
+/// Sensitive settings change, executed through the timelock queue or signer approval
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GovernanceAction {
+    UpdateProtocolFee(i64),
+    UpdateProtocolAddress(ProtocolAddressType, Address),
+    UpgradeLpManager(BytesN<32>),
+    UpdateTimelockDelay(u64),
+    Pause,
+    Unpause,
+    UpdateFeeDistribution(Vec<FeeRecipient>),
+    /// Token and its protocol fee in basis points
+    SetTokenFee(Address, i64),
+    RemoveTokenFee(Address),
+    GrantRole(Role, Address),
+    RevokeRole(Role, Address),
+    AddSupportedToken(Address),
+    RemoveSupportedToken(Address),
+    /// Maximum order lifetime in seconds
+    SetMaxOrderDuration(u64),
+    /// Withdraws a queued action before it executes
+    CancelAction(u64),
+    SetFeeTiers(Vec<FeeTier>),
+    /// Token, minimum fee, maximum fee
+    SetTokenFeeBounds(Address, i128, i128),
+    RemoveTokenFeeBounds(Address),
+    /// Liquidity provider, fee in basis points, optional expiry timestamp
+    SetLpFeeOverride(Address, i64, Option<u64>),
+    RemoveLpFeeOverride(Address),
+    UpdateConfig(ProtocolConfigUpdate),
+    SetCancellationFee(i64),
+    /// New primary relayer and grace period, in ledgers, for the old key
+    RotateRelayer(Address, u32),
+    /// New signer set and approval threshold (signer proposals only)
+    UpdateSignerSet(Vec<Address>, u32),
+}
+
+/// Governance action waiting for its timelock to elapse
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAction {
+    pub id: u64,
+    pub action: GovernanceAction,
+    /// Earliest ledger timestamp at which the action can be executed
+    pub eta: u64,
+}
+
+/// Signer proposal collecting approvals towards the threshold
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigProposal {
+    pub id: u64,
+    pub action: GovernanceAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub enum DataKey {
     Admin,
+    PendingAdmin,
     Treasury,
     Relayer,
     ProtocolFeePercent,
     MaxBps,
     Paused,
     TokenSupported(Address),
+    SupportedTokens,
+    TokenFeePercent(Address),
+    RoleMembers(Role),
+    TimelockDelay,
+    NextActionId,
+    QueuedAction(u64),
+    Signers,
+    SignerThreshold,
+    NextProposalId,
+    Proposal(u64),
+    /// Ledger timestamp from which a proposal can no longer be approved or executed
+    ProposalExpiry(u64),
+    OperationPaused(PauseOperation),
+    TokenPaused(Address),
+    ScheduledFees,
+    FeeTiers,
+    TokenFeeBounds(Address),
+    LpFeeOverride(Address),
+    FeeDistribution,
+    /// Volume limit for a token, or the global limit across all tokens when `None`
+    VolumeLimit(Option<Address>),
+    Denylisted(Address),
+    Allowlisted(Address),
+    AllowlistOnly,
+    ApprovedRefundAddress(Address),
+    RetiringRelayer,
+    /// Set while the primary relayer also held the Relayer role in its own right
+    RelayerRoleGranted,
+    MaxOrderDuration,
+    CancellationFeePercent,
 }
@@ -24,4 +24,17 @@ pub enum ContractError {
     UsdcNotSet = 19,
     NoPendingSettlement = 20,
     NoPendingRefund = 21,
+    TokenNotSupported = 22,
+    NoPendingAdmin = 23,
+    VolumeLimitExceeded = 24,
+    CircuitBreakerTripped = 25,
+    SenderNotAllowed = 26,
+    RefundAddressNotApproved = 27,
+    OrderFrozen = 28,
+    OrderNotFrozen = 29,
+    InvalidExpiry = 30,
+    OrderExpired = 31,
+    OrderNotExpired = 32,
+    OrderCancelled = 33,
+    OrderPartiallySettled = 34,
 }
@@ -0,0 +1,361 @@
+use soroban_sdk::{contracttype, Address, Bytes, Env, String, Symbol};
+
+pub use liquidity_manager::events::{
+    AdminTransferCancelledEvent, AdminTransferProposedEvent, AdminTransferredEvent, EVENT_VERSION,
+};
+
+/// Version of the `OrderSettled` and `SettlementTransferred` payloads
+///
+/// - 1: Initial typed events
+/// - 2: Full monetary breakdown
+/// - 3: Pending settlement `sequence`
+pub const SETTLEMENT_EVENT_VERSION: u32 = 3;
+
+/// Version of the `OrderRefunded` and `RefundTransferred` payloads
+///
+/// - 1: Initial typed events
+/// - 2: Full monetary breakdown
+pub const REFUND_EVENT_VERSION: u32 = 2;
+
+/// Order created and funds moved to the temporary wallet
+///
+/// Topics: `("OrderCreated", order_id, sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderCreatedEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub protocol_fee: i128,
+    pub rate: i64,
+    pub temporary_wallet_address: Address,
+    pub refund_address: Address,
+    pub message_hash: String,
+}
+
+impl OrderCreatedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "OrderCreated"),
+                self.order_id.clone(),
+                self.sender.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Settlement chunk recorded, awaiting `execute_settlement_transfer`
+///
+/// `gross_amount` splits into `protocol_fee` and `net_amount`, paid to `recipient`.
+/// `remaining_amount` and `remaining_bps` describe the order afterwards.
+/// `sequence` identifies the pending settlement for `execute_settlement_sequence`.
+///
+/// Topics: `("OrderSettled", order_id, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderSettledEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub sequence: u32,
+    pub token: Address,
+    pub recipient: Address,
+    pub settle_percent: i128,
+    pub gross_amount: i128,
+    pub protocol_fee: i128,
+    pub net_amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_bps: i128,
+}
+
+impl OrderSettledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "OrderSettled"),
+                self.order_id.clone(),
+                self.recipient.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Settlement chunk paid out
+///
+/// `gross_amount` splits into `protocol_fee` and `net_amount`, paid to `recipient`.
+/// `remaining_amount` and `remaining_bps` describe the order afterwards.
+/// `sequence` matches the `OrderSettledEvent` that queued the chunk.
+///
+/// Topics: `("SettlementTransferred", order_id, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementTransferredEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub sequence: u32,
+    pub token: Address,
+    pub recipient: Address,
+    pub settle_percent: i128,
+    pub gross_amount: i128,
+    pub protocol_fee: i128,
+    pub net_amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_bps: i128,
+}
+
+impl SettlementTransferredEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "SettlementTransferred"),
+                self.order_id.clone(),
+                self.recipient.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Refund recorded, awaiting `execute_refund_transfer`
+///
+/// `gross_amount` splits into `protocol_fee` and `net_amount`, paid to `recipient`.
+/// `remaining_amount` and `remaining_bps` describe the order afterwards.
+///
+/// Topics: `("OrderRefunded", order_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderRefundedEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub token: Address,
+    pub recipient: Address,
+    pub gross_amount: i128,
+    pub protocol_fee: i128,
+    pub net_amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_bps: i128,
+}
+
+impl OrderRefundedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "OrderRefunded"), self.order_id.clone()),
+            self,
+        );
+    }
+}
+
+/// Refund paid out
+///
+/// `gross_amount` splits into `protocol_fee` and `net_amount`, paid to `recipient`.
+/// `remaining_amount` and `remaining_bps` describe the order afterwards.
+///
+/// Topics: `("RefundTransferred", order_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundTransferredEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub token: Address,
+    pub recipient: Address,
+    pub gross_amount: i128,
+    pub protocol_fee: i128,
+    pub net_amount: i128,
+    pub remaining_amount: i128,
+    pub remaining_bps: i128,
+}
+
+impl RefundTransferredEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "RefundTransferred"), self.order_id.clone()),
+            self,
+        );
+    }
+}
+
+/// Share of a protocol fee paid to one fee recipient
+///
+/// Topics: `("FeeDistributed", order_id, recipient)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeDistributedEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+impl FeeDistributedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "FeeDistributed"),
+                self.order_id.clone(),
+                self.recipient.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Volume limit reached its auto-pause threshold; `token` is `None` for the global limit
+///
+/// Topics: `("CircuitBreakerTripped", token)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerTrippedEvent {
+    pub version: u32,
+    pub token: Option<Address>,
+    pub volume: i128,
+}
+
+impl CircuitBreakerTrippedEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "CircuitBreakerTripped"),
+                self.token.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Circuit breaker cleared by the admin; `token` is `None` for the global breaker
+///
+/// Topics: `("CircuitBreakerReset", admin)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitBreakerResetEvent {
+    pub version: u32,
+    pub admin: Address,
+    pub token: Option<Address>,
+}
+
+impl CircuitBreakerResetEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "CircuitBreakerReset"), self.admin.clone()),
+            self,
+        );
+    }
+}
+
+/// Liquidity provider node registered
+///
+/// Topics: `("LpNodeRegistered", lp_node_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LpNodeRegisteredEvent {
+    pub version: u32,
+    pub lp_node_id: Bytes,
+    pub capacity: i128,
+}
+
+impl LpNodeRegisteredEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "LpNodeRegistered"),
+                self.lp_node_id.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Compliance hold placed on an order
+///
+/// Topics: `("OrderFrozen", order_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderFrozenEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub reason: u32,
+}
+
+impl OrderFrozenEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "OrderFrozen"), self.order_id.clone()),
+            self,
+        );
+    }
+}
+
+/// Compliance hold lifted from an order
+///
+/// Topics: `("OrderUnfrozen", order_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderUnfrozenEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub reason: u32,
+}
+
+impl OrderUnfrozenEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (Symbol::new(env, "OrderUnfrozen"), self.order_id.clone()),
+            self,
+        );
+    }
+}
+
+/// Refund of an order redirected by compliance
+///
+/// Topics: `("RefundDestinationSet", order_id)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundDestinationSetEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub destination: Address,
+}
+
+impl RefundDestinationSetEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "RefundDestinationSet"),
+                self.order_id.clone(),
+            ),
+            self,
+        );
+    }
+}
+
+/// Order cancelled by its sender; the refund is staged for `execute_refund_transfer`
+///
+/// Topics: `("OrderCancelled", order_id, sender)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderCancelledEvent {
+    pub version: u32,
+    pub order_id: Bytes,
+    pub sender: Address,
+    pub token: Address,
+    pub cancellation_fee: i128,
+    pub refund_amount: i128,
+}
+
+impl OrderCancelledEvent {
+    pub fn publish(self, env: &Env) {
+        env.events().publish(
+            (
+                Symbol::new(env, "OrderCancelled"),
+                self.order_id.clone(),
+                self.sender.clone(),
+            ),
+            self,
+        );
+    }
+}
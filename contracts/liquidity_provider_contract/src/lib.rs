@@ -1,5 +1,6 @@
 #![no_std]
 mod error;
+pub mod events;
 pub mod liquidity_provider;
 pub mod liquidity_provider_trait;
 mod storage_types;
@@ -1,10 +1,28 @@
 use crate::{
     error::ContractError,
+    events::{
+        AdminTransferCancelledEvent, AdminTransferProposedEvent, AdminTransferredEvent,
+        CircuitBreakerResetEvent, CircuitBreakerTrippedEvent, FeeDistributedEvent,
+        LpNodeRegisteredEvent, OrderCancelledEvent, OrderCreatedEvent, OrderFrozenEvent,
+        OrderRefundedEvent, OrderSettledEvent, OrderUnfrozenEvent, RefundDestinationSetEvent,
+        RefundTransferredEvent, SettlementTransferredEvent, EVENT_VERSION, REFUND_EVENT_VERSION,
+        SETTLEMENT_EVENT_VERSION,
+    },
     liquidity_provider_trait::IGateway,
-    storage_types::{DataKey, LpNode, Order, OrderParams, PendingRefund, PendingSettlement},
+    storage_types::{
+        DataKey, LegacyPendingSettlement, LpNode, Order, OrderParams, PendingRefund,
+        PendingSettlement, SettlementInstruction, SettlementResult, VolumeBucket, VolumeWindow,
+    },
 };
-use liquidity_manager::{self, liquidity_manager::LPSettingManagerContractClient};
-use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, Map};
+use liquidity_manager::{
+    self,
+    liquidity_manager::LPSettingManagerContractClient,
+    storage::{PauseOperation, Role},
+};
+use soroban_sdk::{contract, contractimpl, token, vec, Address, Bytes, BytesN, Env, Map, Vec};
+
+/// Number of buckets a volume limit window is split into
+pub const VOLUME_WINDOW_BUCKETS: u64 = 12;
 
 /// # Liquidity Provider Contract
 ///
@@ -15,11 +33,12 @@ use soroban_sdk::{contract, contractimpl, token, Address, Bytes, BytesN, Env, Ma
 /// - **Two-Step Settlement**: Separate state updates from token transfers
 /// - **Non-Custodial**: Temporary wallets hold funds, not the contract
 /// - **Partial Settlements**: Orders can be settled in multiple chunks
+/// - **Multi-Token**: Orders carry their own token, checked against the settings registry
 /// - **Secure Refunds**: Guaranteed refund mechanism for failed orders
 ///
 /// ## Security Model:
 /// - **Sender**: Authorizes order creation and fund transfer to temporary wallet
-/// - **Relayer**: Authorizes settlement/refund state changes
+/// - **Relayer**: Any holder of the settings manager's Relayer role; authorizes settlement/refund state changes
 /// - **Temporary Wallet**: Authorizes actual token transfers
 ///
 /// ## State Transitions:
@@ -41,13 +60,19 @@ impl IGateway for LPContract {
     /// - `params.sender`: Must authorize the token transfer
     ///
     /// ## Validation:
-    /// - Contract must not be paused
+    /// - Order creation must not be paused (globally, per operation or per token)
+    /// - Circuit breaker must not be tripped
+    /// - Sender must pass the settings manager's compliance screening
+    /// - Token must be supported by the settings manager
     /// - Amount must be positive
     /// - Message hash must not be empty
+    /// - Expiry must be in the future and within the settings manager's maximum order duration
     /// - Order ID must not already exist
+    /// - Amount must fit within the token and global volume limits
     ///
     /// ## Events:
-    /// - `("OrderCreated", order_id, sender)` with order details
+    /// - `OrderCreatedEvent` under `("OrderCreated", order_id, sender)`
+    /// - `CircuitBreakerTrippedEvent` under `("CircuitBreakerTripped", token)`, if a limit's auto-pause threshold is reached
     ///
     /// ## Parameters:
     /// - `env`: Soroban environment
@@ -67,11 +92,25 @@ impl IGateway for LPContract {
 
         let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        // Check if contract is paused
-        if settings_client.is_paused() {
+        // Check if order creation is paused globally or for this token
+        if settings_client.is_operation_paused(&PauseOperation::CreateOrder, &params.token) {
             return Err(ContractError::Paused);
         }
 
+        if Self::is_circuit_breaker_tripped(env.clone(), Some(params.token.clone()))
+            || Self::is_circuit_breaker_tripped(env.clone(), None)
+        {
+            return Err(ContractError::CircuitBreakerTripped);
+        }
+
+        if !settings_client.is_sender_allowed(&params.sender) {
+            return Err(ContractError::SenderNotAllowed);
+        }
+
+        if !settings_client.is_token_supported(&params.token) {
+            return Err(ContractError::TokenNotSupported);
+        }
+
         // Validate input parameters
         if params.amount <= 0 {
             return Err(ContractError::InvalidAmount);
@@ -81,6 +120,15 @@ impl IGateway for LPContract {
             return Err(ContractError::InvalidMessageHash);
         }
 
+        let now = env.ledger().timestamp();
+        let latest_expiry = now
+            .checked_add(settings_client.get_max_order_duration())
+            .ok_or(ContractError::InvalidExpiry)?;
+        let expires_at = params.expires_at.unwrap_or(latest_expiry);
+        if expires_at <= now || expires_at > latest_expiry {
+            return Err(ContractError::InvalidExpiry);
+        }
+
         // Ensure order doesn't already exist
         let order_exists: Option<Order> = env
             .storage()
@@ -91,12 +139,21 @@ impl IGateway for LPContract {
             return Err(ContractError::OrderAlreadyExists);
         }
 
-        let usdc_asset: Address = env.storage().persistent().get(&DataKey::Usdc).unwrap();
-        let token_client = token::Client::new(&env, &usdc_asset);
+        // Record volume against the token limit, then the global limit
+        LPContract::record_volume(
+            &env,
+            &settings_client,
+            Some(params.token.clone()),
+            params.amount,
+        )?;
+        LPContract::record_volume(&env, &settings_client, None, params.amount)?;
 
-        // Calculate protocol fee (currently 1%)
-        let (protocol_fee_percent, max_bps) = settings_client.get_fee_details();
-        let protocol_fee = (params.amount * protocol_fee_percent as i128) / max_bps as i128;
+        let token_client = token::Client::new(&env, &params.token);
+
+        // Calculate protocol fee from the token fee, volume tier or global fee
+        let protocol_fee =
+            LPContract::order_protocol_fee(&settings_client, &params.token, params.amount);
+        let (_, max_bps) = settings_client.get_fee_details();
 
         // Transfer funds from sender to temporary wallet
         token_client.transfer(
@@ -109,7 +166,7 @@ impl IGateway for LPContract {
         let order = Order {
             order_id: params.order_id.clone(),
             sender: params.sender.clone(),
-            token: usdc_asset,
+            token: params.token.clone(),
             amount: params.amount,
             temporary_wallet_address: params.temporary_wallet_address,
             protocol_fee,
@@ -124,6 +181,9 @@ impl IGateway for LPContract {
         env.storage()
             .persistent()
             .set(&DataKey::Order(params.order_id.clone()), &order);
+        env.storage()
+            .persistent()
+            .set(&DataKey::OrderExpiry(params.order_id.clone()), &expires_at);
 
         // Update sender nonce for replay protection
         let mut nonces: Map<Address, i128> = env
@@ -139,16 +199,19 @@ impl IGateway for LPContract {
         env.storage().persistent().set(&DataKey::Nonces, &nonces);
 
         // Emit creation event
-        env.events().publish(
-            ("OrderCreated", params.order_id, params.sender),
-            (
-                params.refund_address,
-                params.amount,
-                protocol_fee,
-                params.rate,
-                params.message_hash,
-            ),
-        );
+        OrderCreatedEvent {
+            version: EVENT_VERSION,
+            order_id: order.order_id,
+            sender: order.sender,
+            token: order.token,
+            amount: order.amount,
+            protocol_fee,
+            rate: order.rate,
+            temporary_wallet_address: order.temporary_wallet_address,
+            refund_address: order.refund_address,
+            message_hash: order.message_hash,
+        }
+        .publish(&env);
         Ok(())
     }
 
@@ -159,22 +222,29 @@ impl IGateway for LPContract {
     /// in the two-step settlement process. No tokens are transferred here.
     ///
     /// ## Authorization:
-    /// - `relayer`: Must authorize the state change
+    /// - `relayer`: Must hold the Relayer role (or be a retiring relayer in its grace period) and authorize the state change
     ///
     /// ## Validation:
-    /// - Order must exist and not be fulfilled/refunded
+    /// - Settlement must not be paused (globally, per operation or per token)
+    /// - Order must exist, not be fulfilled/refunded and not be frozen
+    /// - Order must not have expired
     /// - Settle percent must be valid (0 < percent ≤ 100,000)
     /// - Order must have sufficient remaining BPS
     ///
+    /// ## Fees:
+    /// - An active fee override for `liquidity_provider` is applied to this chunk
+    /// - Otherwise the chunk pays its share of the order's bounded fee
+    ///
     /// ## State Changes:
     /// - Updates order amount and current_bps
     /// - Marks order as fulfilled if current_bps reaches 0
-    /// - Stores pending settlement for transfer execution
+    /// - Queues a pending settlement under the order's next sequence
     ///
     /// ## Events:
-    /// - `("OrderSettled", order_id, liquidity_provider)` with settle_percent
+    /// - `OrderSettledEvent` under `("OrderSettled", order_id, liquidity_provider)` with the full breakdown
     ///
     /// ## Parameters:
+    /// - `relayer`: Relayer submitting the settlement
     /// - `order_id`: Unique identifier for the order
     /// - `liquidity_provider`: Address to receive settled funds
     /// - `settle_percent`: Percentage to settle (in basis points, 100,000 = 100%)
@@ -184,6 +254,7 @@ impl IGateway for LPContract {
     /// - `Err(ContractError)` on failure
     fn settle(
         env: Env,
+        relayer: Address,
         order_id: Bytes,
         liquidity_provider: Address,
         settle_percent: i128,
@@ -196,82 +267,18 @@ impl IGateway for LPContract {
 
         let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        if settings_client.is_paused() {
-            return Err(ContractError::Paused);
-        }
-
-        let relayer: Address = settings_client.get_relayer_address();
         relayer.require_auth();
-
-        // Validate settle percentage (0 < percent ≤ 100,000)
-        if settle_percent <= 0 || settle_percent > 100_000 {
-            return Err(ContractError::InvalidSettlePercent);
-        }
-
-        let order_option: Option<Order> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Order(order_id.clone()));
-
-        if order_option.is_none() {
-            return Err(ContractError::OrderNotFound);
-        }
-
-        let mut order: Order = order_option.unwrap();
-
-        if order.current_bps == 0 {
-            return Err(ContractError::OrderFulfilled);
-        }
-
-        // Prevent double-settlement and settlement of refunded orders
-        if order.is_fulfilled {
-            return Err(ContractError::OrderFulfilled);
-        }
-
-        if order.is_refunded {
-            return Err(ContractError::OrderRefunded);
-        }
-
-        // Calculate settlement amounts
-        let current_order_bps = order.current_bps;
-        order.current_bps -= settle_percent;
-
-        let liquidity_provider_amount = (order.amount * settle_percent) / current_order_bps;
-        order.amount -= liquidity_provider_amount;
-
-        let (protocol_fee_percent, max_bps) = settings_client.get_fee_details();
-        let protocol_fee =
-            (liquidity_provider_amount * protocol_fee_percent as i128) / (max_bps as i128);
-        let transfer_amount = liquidity_provider_amount - protocol_fee;
-
-        // Mark as fulfilled if completely settled
-        if order.current_bps == 0 {
-            order.is_fulfilled = true;
+        if !settings_client.is_relayer_authorized(&relayer) {
+            return Err(ContractError::Unauthorized);
         }
 
-        // Store pending settlement for transfer execution
-        let pending_settlement = PendingSettlement {
-            order_id: order_id.clone(),
-            protocol_fee,
-            transfer_amount,
-            liquidity_provider: liquidity_provider.clone(),
-            settle_percent,
-        };
-
-        env.storage().persistent().set(
-            &DataKey::PendingSettlement(order_id.clone()),
-            &pending_settlement,
-        );
-
-        // Update order state
-        env.storage()
-            .persistent()
-            .set(&DataKey::Order(order_id.clone()), &order);
-
-        env.events().publish(
-            ("OrderSettled", order_id, liquidity_provider),
+        LPContract::record_settlement(
+            &env,
+            &settings_client,
+            order_id,
+            liquidity_provider,
             settle_percent,
-        );
+        )?;
 
         Ok(true)
     }
@@ -279,39 +286,74 @@ impl IGateway for LPContract {
     /// # Execute Settlement Transfers (Step 2: Token Transfer)
     ///
     /// ## Description:
-    /// Executes the actual token transfers for a previously settled order.
-    /// This is the second step in the two-step settlement process.
+    /// Executes the token transfers for every outstanding settlement of an order,
+    /// oldest first. This is the second step in the two-step settlement process.
     ///
     /// ## Authorization:
     /// - `order.temporary_wallet_address`: Must authorize the token transfers
     ///
-    /// ## Transfers:
-    /// 1. Protocol fee to treasury (if any)
+    /// ## Transfers (in the order's token), per pending settlement:
+    /// 1. Protocol fee split across the fee distribution (if any)
     /// 2. Remaining amount to liquidity provider
     ///
-    /// ## Events:
-    /// - `("SettlementTransferred", order_id)` with settle_percent
+    /// ## Events, per pending settlement:
+    /// - `FeeDistributedEvent` under `("FeeDistributed", order_id, recipient)`, per fee recipient
+    /// - `SettlementTransferredEvent` under `("SettlementTransferred", order_id, liquidity_provider)` with the full breakdown
     ///
     /// ## Note:
-    /// - Only executes if pending settlement exists
-    /// - Clears pending settlement after execution
+    /// - Only executes if at least one pending settlement exists
+    /// - Blocked by the SettlementTransfer or token pause, not by the global pause
+    /// - Rejected with `OrderFrozen` while the order is under a compliance hold
+    /// - Clears the pending settlements after execution
     /// - Temporary wallet maintains control of funds until this point
     fn execute_settlement_transfer(env: Env, order_id: Bytes) -> Result<(), ContractError> {
-        let order: Order = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Order(order_id.clone()))
-            .ok_or(ContractError::OrderNotFound)?;
+        let sequences = LPContract::pending_sequences(&env, &order_id);
 
-        // Temporary wallet must authorize the transfers
-        order.temporary_wallet_address.require_auth();
+        LPContract::transfer_settlements(&env, &order_id, &sequences)
+    }
 
-        let pending_settlement: PendingSettlement = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingSettlement(order_id.clone()))
-            .ok_or(ContractError::NoPendingSettlement)?;
+    /// # Execute a Single Settlement Transfer
+    ///
+    /// ## Description:
+    /// Same as `execute_settlement_transfer`, but only for the settlement recorded
+    /// under `sequence`, leaving the order's other pending settlements in place.
+    ///
+    /// ## Authorization:
+    /// - `order.temporary_wallet_address`: Must authorize the token transfers
+    ///
+    /// ## Errors:
+    /// - `NoPendingSettlement` if nothing is outstanding under `sequence`
+    fn execute_settlement_sequence(
+        env: Env,
+        order_id: Bytes,
+        sequence: u32,
+    ) -> Result<(), ContractError> {
+        LPContract::transfer_settlements(&env, &order_id, &vec![&env, sequence])
+    }
 
+    /// # Initiate Order Refund (Step 1: State Update)
+    ///
+    /// ## Description:
+    /// Marks an order for refund and calculates refund amounts.
+    /// This is the first step in the two-step refund process.
+    ///
+    /// ## Authorization:
+    /// - `relayer`: Must hold the Relayer role (or be a retiring relayer in its grace period) and authorize the state change
+    ///
+    /// ## Validation:
+    /// - Refunds must not be paused (globally, per operation or per token)
+    /// - Order must exist, not be fulfilled/refunded and not be frozen
+    /// - Refund address must be approved if the sender is denylisted
+    /// - Fee must not exceed accumulated protocol fee
+    ///
+    /// ## State Changes:
+    /// - Marks order as refunded
+    /// - Zeros out order amounts
+    /// - Stores pending refund for transfer execution
+    ///
+    /// ## Events:
+    /// - `OrderRefundedEvent` under `("OrderRefunded", order_id)` with the full breakdown
+    fn refund(env: Env, relayer: Address, order_id: Bytes, fee: i128) -> Result<(), ContractError> {
         let settings_contract: Address = env
             .storage()
             .persistent()
@@ -319,62 +361,39 @@ impl IGateway for LPContract {
             .unwrap();
         let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        let treasury: Address = settings_client.get_treasury_address();
-        let usdc_asset: Address = env.storage().persistent().get(&DataKey::Usdc).unwrap();
-        let token_client = token::Client::new(&env, &usdc_asset);
-
-        // Transfer protocol fee to treasury
-        if pending_settlement.protocol_fee > 0 {
-            token_client.transfer(
-                &order.temporary_wallet_address,
-                &treasury,
-                &pending_settlement.protocol_fee,
-            );
-        }
-
-        // Transfer remaining amount to liquidity provider
-        if pending_settlement.transfer_amount > 0 {
-            token_client.transfer(
-                &order.temporary_wallet_address,
-                &pending_settlement.liquidity_provider,
-                &pending_settlement.transfer_amount,
-            );
+        relayer.require_auth();
+        if !settings_client.is_relayer_authorized(&relayer) {
+            return Err(ContractError::Unauthorized);
         }
 
-        // Clear pending settlement to prevent re-execution
-        env.storage()
+        let order: Order = env
+            .storage()
             .persistent()
-            .remove(&DataKey::PendingSettlement(order_id.clone()));
-
-        env.events().publish(
-            ("SettlementTransferred", order_id),
-            pending_settlement.settle_percent,
-        );
+            .get(&DataKey::Order(order_id.clone()))
+            .ok_or(ContractError::OrderNotFound)?;
 
-        Ok(())
+        LPContract::stage_refund(&env, &settings_client, order, fee)
     }
 
-    /// # Initiate Order Refund (Step 1: State Update)
+    /// # Refund an Expired Order (Step 1: State Update)
     ///
     /// ## Description:
-    /// Marks an order for refund and calculates refund amounts.
-    /// This is the first step in the two-step refund process.
+    /// Stages a refund of the unsettled remainder once an order has expired, so funds
+    /// never stay stuck if the relayer does not act. No protocol fee is charged.
     ///
     /// ## Authorization:
-    /// - `relayer`: Must authorize the state change
+    /// - None; anyone (typically the sender) may call this after expiry
     ///
     /// ## Validation:
-    /// - Order must exist and not be fulfilled/refunded
-    /// - Fee must not exceed accumulated protocol fee
-    ///
-    /// ## State Changes:
-    /// - Marks order as refunded
-    /// - Zeros out order amounts
-    /// - Stores pending refund for transfer execution
+    /// - Order must have reached its expiry; orders without one never expire
+    /// - Same checks as `refund` (pause, freeze, status and compliance)
     ///
     /// ## Events:
-    /// - `("OrderRefunded", order_id)` with fee amount
-    fn refund(env: Env, order_id: Bytes, fee: i128) -> Result<(), ContractError> {
+    /// - `OrderRefundedEvent` under `("OrderRefunded", order_id)` with the full breakdown
+    ///
+    /// ## Note:
+    /// - The temporary wallet still executes the transfer via `execute_refund_transfer`
+    fn refund_expired(env: Env, order_id: Bytes) -> Result<(), ContractError> {
         let settings_contract: Address = env
             .storage()
             .persistent()
@@ -382,54 +401,91 @@ impl IGateway for LPContract {
             .unwrap();
         let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        if settings_client.is_paused() {
-            return Err(ContractError::Paused);
+        let order: Order = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Order(order_id.clone()))
+            .ok_or(ContractError::OrderNotFound)?;
+
+        let expiry: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OrderExpiry(order_id.clone()));
+        if expiry.is_none_or(|expires_at| env.ledger().timestamp() < expires_at) {
+            return Err(ContractError::OrderNotExpired);
         }
 
-        let relayer: Address = settings_client.get_relayer_address();
-        relayer.require_auth();
+        LPContract::stage_refund(&env, &settings_client, order, 0)
+    }
 
-        let mut order: Order = env
+    /// # Cancel an Unsettled Order (Step 1: State Update)
+    ///
+    /// ## Description:
+    /// Lets the sender unwind an order nobody has started settling, e.g. one created
+    /// by mistake. Stages a refund to `refund_address` minus the cancellation fee.
+    ///
+    /// ## Authorization:
+    /// - `order.sender`: Must authorize the cancellation
+    ///
+    /// ## Validation:
+    /// - Order must be untouched: `current_bps` at max BPS and no pending settlement
+    /// - Same checks as `refund` (pause, freeze, status and compliance)
+    ///
+    /// ## Events:
+    /// - `OrderRefundedEvent` under `("OrderRefunded", order_id)` with the full breakdown
+    /// - `OrderCancelledEvent` under `("OrderCancelled", order_id, sender)`
+    ///
+    /// ## Note:
+    /// - The fee is the settings manager's cancellation fee, capped at the order's protocol fee
+    /// - The temporary wallet still executes the transfer via `execute_refund_transfer`
+    fn cancel_order(env: Env, order_id: Bytes) -> Result<(), ContractError> {
+        let order: Order = env
             .storage()
             .persistent()
             .get(&DataKey::Order(order_id.clone()))
             .ok_or(ContractError::OrderNotFound)?;
 
-        // Prevent refund of fulfilled or already refunded orders
-        if order.is_fulfilled {
-            return Err(ContractError::OrderFulfilled);
-        }
+        order.sender.require_auth();
 
-        if order.is_refunded {
-            return Err(ContractError::OrderRefunded);
-        }
+        let settings_contract: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SettingsContract)
+            .unwrap();
+        let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        // Validate refund fee doesn't exceed protocol fee
-        if fee > order.protocol_fee {
-            return Err(ContractError::FeeExceedsProtocolFee);
+        if LPContract::order_cancelled(&env, &order_id) {
+            return Err(ContractError::OrderCancelled);
         }
 
-        // Calculate and store pending refund
-        let pending_refund = PendingRefund {
-            order_id: order_id.clone(),
-            fee,
-            refund_amount: (order.amount) - fee,
-        };
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingRefund(order_id.clone()), &pending_refund);
+        let (_, max_bps) = settings_client.get_fee_details();
+        if order.current_bps != max_bps as i128
+            || !LPContract::pending_sequences(&env, &order_id).is_empty()
+        {
+            return Err(ContractError::OrderPartiallySettled);
+        }
 
-        // Update order state to refunded
-        order.is_refunded = true;
-        order.current_bps = 0;
-        order.amount = 0;
+        let fee_percent = settings_client.get_cancellation_fee();
+        let cancellation_fee =
+            ((order.amount * fee_percent as i128) / max_bps as i128).min(order.protocol_fee);
+        let refund_amount = order.amount - cancellation_fee;
+        let sender = order.sender.clone();
+        let token = order.token.clone();
 
+        LPContract::stage_refund(&env, &settings_client, order, cancellation_fee)?;
         env.storage()
             .persistent()
-            .set(&DataKey::Order(order_id.clone()), &order);
-
-        env.events().publish(("OrderRefunded", order_id), fee);
+            .set(&DataKey::OrderCancelled(order_id.clone()), &true);
+
+        OrderCancelledEvent {
+            version: EVENT_VERSION,
+            order_id,
+            sender,
+            token,
+            cancellation_fee,
+            refund_amount,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -443,12 +499,19 @@ impl IGateway for LPContract {
     /// ## Authorization:
     /// - `order.temporary_wallet_address`: Must authorize the token transfers
     ///
-    /// ## Transfers:
-    /// 1. Protocol fee to treasury (if any)
+    /// ## Transfers (in the order's token):
+    /// 1. Protocol fee split across the fee distribution (if any)
     /// 2. Remaining amount to refund address
     ///
+    /// ## Events:
+    /// - `FeeDistributedEvent` under `("FeeDistributed", order_id, recipient)`, per fee recipient
+    /// - `RefundTransferredEvent` under `("RefundTransferred", order_id)` with the full breakdown
+    ///
     /// ## Note:
     /// - Only executes if pending refund exists
+    /// - Blocked by the RefundTransfer or token pause, not by the global pause
+    /// - Rejected with `OrderFrozen` while the order is under a compliance hold
+    /// - Fails if the sender is denylisted and the refund address is not approved
     /// - Clears pending refund after execution
     fn execute_refund_transfer(env: Env, order_id: Bytes) -> Result<(), ContractError> {
         let order: Order = env
@@ -472,23 +535,32 @@ impl IGateway for LPContract {
             .unwrap();
         let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        let treasury: Address = settings_client.get_treasury_address();
-        let usdc_asset: Address = env.storage().persistent().get(&DataKey::Usdc).unwrap();
-        let token_client = token::Client::new(&env, &usdc_asset);
+        if settings_client.is_operation_paused(&PauseOperation::RefundTransfer, &order.token) {
+            return Err(ContractError::Paused);
+        }
 
-        // Transfer protocol fee to treasury
-        if pending_refund.fee > 0 {
-            token_client.transfer(
-                &order.temporary_wallet_address,
-                &treasury,
-                &pending_refund.fee,
-            );
+        if LPContract::order_frozen(&env, &order.order_id) {
+            return Err(ContractError::OrderFrozen);
         }
 
-        // Transfer remaining amount to refund address
+        // Sender may have been denylisted after the refund was initiated
+        let recipient = LPContract::refund_recipient(&env, &settings_client, &order)?;
+
+        let token_client = token::Client::new(&env, &order.token);
+
+        // Split protocol fee across the fee distribution
+        LPContract::distribute_fee(
+            &env,
+            &settings_client,
+            &token_client,
+            &order,
+            pending_refund.fee,
+        );
+
+        // Transfer remaining amount to the refund recipient
         token_client.transfer(
             &order.temporary_wallet_address,
-            &order.refund_address,
+            &recipient,
             &pending_refund.refund_amount,
         );
 
@@ -497,43 +569,181 @@ impl IGateway for LPContract {
             .persistent()
             .remove(&DataKey::PendingRefund(order_id.clone()));
 
-        env.events().publish(
-            ("RefundTransferred", order_id),
-            pending_refund.refund_amount,
-        );
+        RefundTransferredEvent {
+            version: REFUND_EVENT_VERSION,
+            order_id,
+            token: order.token,
+            recipient,
+            gross_amount: pending_refund.fee + pending_refund.refund_amount,
+            protocol_fee: pending_refund.fee,
+            net_amount: pending_refund.refund_amount,
+            // Staging the refund cleared the order, and nothing can settle it afterwards
+            remaining_amount: 0,
+            remaining_bps: 0,
+        }
+        .publish(&env);
 
         Ok(())
     }
 
-    /// # Get token balance for a user
+    /// # Settle Many Orders (Step 1: State Update)
     ///
-    /// ## Returns:
-    /// - USDC balance of the specified user
-    fn get_token_balance(env: Env, user: Address) -> i128 {
-        let usdc_asset: Address = env.storage().persistent().get(&DataKey::Usdc).unwrap();
-        let token_client = token::Client::new(&env, &usdc_asset);
-        token_client.balance(&user)
-    }
-
-    /// # Get order ID (validation function)
+    /// ## Description:
+    /// Applies several settlements in one transaction. Each instruction goes through
+    /// the same validation and math as `settle`; a rejected instruction is reported
+    /// in the results and leaves its order untouched instead of aborting the batch.
+    ///
+    /// ## Authorization:
+    /// - `relayer`: Same as `settle`, checked once for the whole batch
+    ///
+    /// ## Validation:
+    /// - `instructions` must not be empty
+    ///
+    /// ## Events:
+    /// - `OrderSettledEvent` for every applied instruction
     ///
     /// ## Returns:
-    /// - Order ID if order exists
-    /// - Error if order not found
-    fn get_order_id(env: Env, order_id: Bytes) -> Result<Bytes, ContractError> {
-        let order: Order = env
+    /// - One `SettlementResult` per instruction, in the same order
+    /// - `Err(ContractError)` only if the relayer check fails or the batch is empty
+    fn batch_settle(
+        env: Env,
+        relayer: Address,
+        instructions: Vec<SettlementInstruction>,
+    ) -> Result<Vec<SettlementResult>, ContractError> {
+        let settings_contract: Address = env
             .storage()
             .persistent()
-            .get(&DataKey::Order(order_id))
-            .ok_or(ContractError::OrderNotFound)?;
+            .get(&DataKey::SettingsContract)
+            .unwrap();
+        let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
 
-        Ok(order.order_id)
+        relayer.require_auth();
+        if !settings_client.is_relayer_authorized(&relayer) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if instructions.is_empty() {
+            return Err(ContractError::InvalidParameter);
+        }
+
+        let mut results = Vec::new(&env);
+        for instruction in instructions.iter() {
+            let outcome = LPContract::record_settlement(
+                &env,
+                &settings_client,
+                instruction.order_id.clone(),
+                instruction.liquidity_provider,
+                instruction.settle_percent,
+            );
+            results.push_back(SettlementResult {
+                order_id: instruction.order_id,
+                sequence: outcome.ok(),
+                error: outcome.err().map(|error| error as u32),
+            });
+        }
+
+        Ok(results)
     }
 
-    /// # Get complete order information
+    /// # Settle and Transfer in One Call
     ///
-    /// ## Returns:
-    /// - Full Order struct if order exists
+    /// ## Description:
+    /// Runs `settle` and pays out the resulting chunk in the same invocation, so order
+    /// state and funds never disagree. Settlements already queued for the order stay
+    /// pending; the two-step flow remains for wallets that sign asynchronously.
+    ///
+    /// ## Authorization:
+    /// - `relayer`: Same as `settle`
+    /// - `order.temporary_wallet_address`: Same as `execute_settlement_transfer`
+    ///
+    /// ## Validation:
+    /// - Everything `settle` and `execute_settlement_transfer` check, including both pauses
+    ///
+    /// ## Events:
+    /// - `OrderSettledEvent`, `FeeDistributedEvent` and `SettlementTransferredEvent`, as in the two-step flow
+    fn settle_and_transfer(
+        env: Env,
+        relayer: Address,
+        order_id: Bytes,
+        liquidity_provider: Address,
+        settle_percent: i128,
+    ) -> Result<(), ContractError> {
+        let settings_contract: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SettingsContract)
+            .unwrap();
+        let settings_client = LPSettingManagerContractClient::new(&env, &settings_contract);
+
+        relayer.require_auth();
+        if !settings_client.is_relayer_authorized(&relayer) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let sequence = LPContract::record_settlement(
+            &env,
+            &settings_client,
+            order_id.clone(),
+            liquidity_provider,
+            settle_percent,
+        )?;
+
+        LPContract::transfer_settlements(&env, &order_id, &vec![&env, sequence])
+    }
+
+    /// # Refund and Transfer in One Call
+    ///
+    /// ## Description:
+    /// Runs `refund` and `execute_refund_transfer` in the same invocation.
+    ///
+    /// ## Authorization:
+    /// - `relayer`: Same as `refund`
+    /// - `order.temporary_wallet_address`: Same as `execute_refund_transfer`
+    ///
+    /// ## Validation:
+    /// - Everything `refund` and `execute_refund_transfer` check, including both pauses
+    ///
+    /// ## Events:
+    /// - `OrderRefundedEvent`, `FeeDistributedEvent` and `RefundTransferredEvent`, as in the two-step flow
+    fn refund_and_transfer(
+        env: Env,
+        relayer: Address,
+        order_id: Bytes,
+        fee: i128,
+    ) -> Result<(), ContractError> {
+        LPContract::refund(env.clone(), relayer, order_id.clone(), fee)?;
+        LPContract::execute_refund_transfer(env, order_id)
+    }
+
+    /// # Get token balance for a user
+    ///
+    /// ## Returns:
+    /// - USDC balance of the specified user
+    fn get_token_balance(env: Env, user: Address) -> i128 {
+        let usdc_asset: Address = env.storage().persistent().get(&DataKey::Usdc).unwrap();
+        let token_client = token::Client::new(&env, &usdc_asset);
+        token_client.balance(&user)
+    }
+
+    /// # Get order ID (validation function)
+    ///
+    /// ## Returns:
+    /// - Order ID if order exists
+    /// - Error if order not found
+    fn get_order_id(env: Env, order_id: Bytes) -> Result<Bytes, ContractError> {
+        let order: Order = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Order(order_id))
+            .ok_or(ContractError::OrderNotFound)?;
+
+        Ok(order.order_id)
+    }
+
+    /// # Get complete order information
+    ///
+    /// ## Returns:
+    /// - Full Order struct if order exists
     /// - Error if order not found
     fn get_order_info(env: Env, order_id: Bytes) -> Result<Order, ContractError> {
         env.storage()
@@ -542,6 +752,41 @@ impl IGateway for LPContract {
             .ok_or(ContractError::OrderNotFound)
     }
 
+    /// # Get Order Expiry
+    ///
+    /// ## Returns:
+    /// - Ledger timestamp from which the order is expired
+    /// - `None` for unknown orders and orders created before expiry existed
+    fn get_order_expiry(env: Env, order_id: Bytes) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OrderExpiry(order_id))
+    }
+
+    /// # Is Order Cancelled
+    ///
+    /// ## Returns:
+    /// - `true` once the sender has cancelled the order with `cancel_order`
+    fn is_order_cancelled(env: Env, order_id: Bytes) -> bool {
+        LPContract::order_cancelled(&env, &order_id)
+    }
+
+    /// # Get Pending Settlements
+    ///
+    /// ## Returns:
+    /// - The order's settlements awaiting `execute_settlement_transfer`, oldest first
+    fn get_pending_settlements(env: Env, order_id: Bytes) -> Vec<PendingSettlement> {
+        let mut pending_settlements = Vec::new(&env);
+        for sequence in LPContract::pending_sequences(&env, &order_id).iter() {
+            if let Some(pending_settlement) =
+                LPContract::pending_settlement(&env, &order_id, sequence)
+            {
+                pending_settlements.push_back(pending_settlement);
+            }
+        }
+        pending_settlements
+    }
+
     /// # Get current fee details from settings
     ///
     /// ## Returns:
@@ -569,7 +814,7 @@ impl LPContract {
     /// - `admin`: Must authorize the initialization
     ///
     /// ## Parameters:
-    /// - `usdc_asset`: Address of the USDC token contract
+    /// - `usdc_asset`: Address of the default (USDC) token contract
     /// - `settings_contract`: Address of the settings manager contract
     pub fn init(env: Env, admin: Address, usdc_asset: Address, settings_contract: Address) {
         let storage = env.storage().persistent();
@@ -586,6 +831,567 @@ impl LPContract {
         storage.set(&DataKey::SettingsContract, &settings_contract);
     }
 
+    /// # Internal: Calculate Order Protocol Fee
+    ///
+    /// ## Description:
+    /// Applies the resolved fee percentage to a full order amount, then clamps the
+    /// result to the token's fee floor and cap. Never exceeds the order amount.
+    fn order_protocol_fee(
+        settings_client: &LPSettingManagerContractClient,
+        token: &Address,
+        order_amount: i128,
+    ) -> i128 {
+        let (protocol_fee_percent, max_bps) =
+            settings_client.get_order_fee_details(token, &order_amount);
+        let mut protocol_fee = (order_amount * protocol_fee_percent as i128) / max_bps as i128;
+
+        if let Some(bounds) = settings_client.get_token_fee_bounds(token) {
+            protocol_fee = protocol_fee.clamp(bounds.min_fee, bounds.max_fee);
+        }
+
+        protocol_fee.min(order_amount)
+    }
+
+    /// # Internal: Record Settlement
+    ///
+    /// ## Description:
+    /// Validates a settlement chunk, updates the order and queues the pending settlement.
+    /// Shared by `settle`, `batch_settle` and `settle_and_transfer`; the caller has
+    /// already authenticated the relayer.
+    ///
+    /// ## Parameters:
+    /// - `order_id`: Order to settle
+    /// - `liquidity_provider`: Address to receive the chunk
+    /// - `settle_percent`: Share of the order to settle (in basis points)
+    ///
+    /// ## Returns:
+    /// - `Ok(sequence)`: Sequence the pending settlement was queued under
+    fn record_settlement(
+        env: &Env,
+        settings_client: &LPSettingManagerContractClient,
+        order_id: Bytes,
+        liquidity_provider: Address,
+        settle_percent: i128,
+    ) -> Result<u32, ContractError> {
+        // Validate settle percentage (0 < percent ≤ 100,000)
+        if settle_percent <= 0 || settle_percent > 100_000 {
+            return Err(ContractError::InvalidSettlePercent);
+        }
+
+        let order_option: Option<Order> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Order(order_id.clone()));
+
+        if order_option.is_none() {
+            return Err(ContractError::OrderNotFound);
+        }
+
+        let mut order: Order = order_option.unwrap();
+
+        if settings_client.is_operation_paused(&PauseOperation::Settle, &order.token) {
+            return Err(ContractError::Paused);
+        }
+
+        if LPContract::order_frozen(env, &order.order_id) {
+            return Err(ContractError::OrderFrozen);
+        }
+
+        if LPContract::order_cancelled(env, &order_id) {
+            return Err(ContractError::OrderCancelled);
+        }
+
+        let expiry: Option<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OrderExpiry(order_id.clone()));
+        if expiry.is_some_and(|expires_at| env.ledger().timestamp() >= expires_at) {
+            return Err(ContractError::OrderExpired);
+        }
+
+        if order.current_bps == 0 {
+            return Err(ContractError::OrderFulfilled);
+        }
+
+        // Earlier chunks may still be pending; never settle more than remains
+        if settle_percent > order.current_bps {
+            return Err(ContractError::InvalidSettlePercent);
+        }
+
+        // Prevent double-settlement and settlement of refunded orders
+        if order.is_fulfilled {
+            return Err(ContractError::OrderFulfilled);
+        }
+
+        if order.is_refunded {
+            return Err(ContractError::OrderRefunded);
+        }
+
+        // Order-level amounts are split across chunks; charging the growth of the amount
+        // accrued on settled bps makes the chunks add up to exactly the order-level total
+        let (_, max_bps) = settings_client.get_fee_details();
+        let max_bps = max_bps as i128;
+        let current_order_bps = order.current_bps;
+        let remaining_bps = current_order_bps - settle_percent;
+        let chunk_share = |total: i128| {
+            (total * (max_bps - remaining_bps)) / max_bps
+                - (total * (max_bps - current_order_bps)) / max_bps
+        };
+
+        // Calculate settlement amounts
+        order.current_bps = remaining_bps;
+        let order_fee_share = chunk_share(order.protocol_fee);
+
+        let liquidity_provider_amount = (order.amount * settle_percent) / current_order_bps;
+        order.amount -= liquidity_provider_amount;
+
+        // Negotiated terms for this liquidity provider replace the regular fee, but stay
+        // within this chunk's share of the token's fee floor and cap
+        let protocol_fee = match settings_client.get_active_lp_fee(&liquidity_provider) {
+            Some(lp_fee_percent) => {
+                let mut lp_fee = (liquidity_provider_amount * lp_fee_percent as i128) / max_bps;
+                if let Some(bounds) = settings_client.get_token_fee_bounds(&order.token) {
+                    lp_fee = lp_fee.clamp(chunk_share(bounds.min_fee), chunk_share(bounds.max_fee));
+                }
+                lp_fee.min(liquidity_provider_amount)
+            }
+            None => order_fee_share,
+        };
+        let transfer_amount = liquidity_provider_amount - protocol_fee;
+
+        // Mark as fulfilled if completely settled
+        if order.current_bps == 0 {
+            order.is_fulfilled = true;
+        }
+
+        // Queue pending settlement for transfer execution alongside earlier ones
+        let storage = env.storage().persistent();
+        let has_legacy = storage.has(&DataKey::PendingSettlement(order_id.clone()));
+        let sequence: u32 = storage
+            .get(&DataKey::NextSettlementSequence(order_id.clone()))
+            .unwrap_or(if has_legacy { 1 } else { 0 });
+        let mut sequences: Vec<u32> = storage
+            .get(&DataKey::PendingSettlementSequences(order_id.clone()))
+            .unwrap_or(Vec::new(env));
+
+        // Move a pre-sequencing settlement to sequence 0 while the stored order still
+        // holds the state it left behind, so its remaining values stay accurate
+        if has_legacy {
+            if let Some(legacy) = LPContract::pending_settlement(env, &order_id, 0) {
+                storage.set(&DataKey::SequencedSettlement(order_id.clone(), 0), &legacy);
+                storage.remove(&DataKey::PendingSettlement(order_id.clone()));
+                sequences.push_front(0);
+            }
+        }
+        sequences.push_back(sequence);
+
+        let pending_settlement = PendingSettlement {
+            order_id: order_id.clone(),
+            sequence,
+            protocol_fee,
+            transfer_amount,
+            liquidity_provider: liquidity_provider.clone(),
+            settle_percent,
+            remaining_amount: order.amount,
+            remaining_bps: order.current_bps,
+        };
+
+        storage.set(
+            &DataKey::SequencedSettlement(order_id.clone(), sequence),
+            &pending_settlement,
+        );
+        storage.set(
+            &DataKey::PendingSettlementSequences(order_id.clone()),
+            &sequences,
+        );
+        storage.set(
+            &DataKey::NextSettlementSequence(order_id.clone()),
+            &(sequence + 1),
+        );
+
+        // Update order state
+        env.storage()
+            .persistent()
+            .set(&DataKey::Order(order_id.clone()), &order);
+
+        OrderSettledEvent {
+            version: SETTLEMENT_EVENT_VERSION,
+            order_id,
+            sequence,
+            token: order.token,
+            recipient: liquidity_provider,
+            settle_percent,
+            gross_amount: liquidity_provider_amount,
+            protocol_fee,
+            net_amount: transfer_amount,
+            remaining_amount: order.amount,
+            remaining_bps: order.current_bps,
+        }
+        .publish(env);
+
+        Ok(sequence)
+    }
+
+    /// # Internal: Pending Settlement Sequences
+    ///
+    /// ## Description:
+    /// Lists the order's outstanding settlements, oldest first. A settlement recorded
+    /// before sequencing existed is reported as sequence 0.
+    fn pending_sequences(env: &Env, order_id: &Bytes) -> Vec<u32> {
+        let storage = env.storage().persistent();
+        let mut sequences: Vec<u32> = storage
+            .get(&DataKey::PendingSettlementSequences(order_id.clone()))
+            .unwrap_or(Vec::new(env));
+        if storage.has(&DataKey::PendingSettlement(order_id.clone())) {
+            sequences.push_front(0);
+        }
+        sequences
+    }
+
+    /// # Internal: Load Pending Settlement
+    ///
+    /// ## Description:
+    /// Loads the pending settlement recorded under `sequence`, falling back to the
+    /// pre-sequencing key for sequence 0.
+    ///
+    /// ## Returns:
+    /// - `None` if no settlement is pending under `sequence`
+    fn pending_settlement(env: &Env, order_id: &Bytes, sequence: u32) -> Option<PendingSettlement> {
+        let storage = env.storage().persistent();
+        if let Some(pending_settlement) =
+            storage.get(&DataKey::SequencedSettlement(order_id.clone(), sequence))
+        {
+            return Some(pending_settlement);
+        }
+        if sequence != 0 {
+            return None;
+        }
+
+        // No settlement has been recorded since, so the order still holds its remaining state
+        let legacy: LegacyPendingSettlement =
+            storage.get(&DataKey::PendingSettlement(order_id.clone()))?;
+        let order: Order = storage.get(&DataKey::Order(order_id.clone()))?;
+        Some(PendingSettlement {
+            order_id: legacy.order_id,
+            sequence: 0,
+            protocol_fee: legacy.protocol_fee,
+            transfer_amount: legacy.transfer_amount,
+            liquidity_provider: legacy.liquidity_provider,
+            settle_percent: legacy.settle_percent,
+            remaining_amount: order.amount,
+            remaining_bps: order.current_bps,
+        })
+    }
+
+    /// # Internal: Transfer Settlements
+    ///
+    /// ## Description:
+    /// Executes the listed pending settlements of an order and drops them from its queue.
+    /// Shared by `execute_settlement_transfer` and `execute_settlement_sequence`.
+    ///
+    /// ## Parameters:
+    /// - `order_id`: Order whose settlements are paid out
+    /// - `sequences`: Pending settlements to execute, in payout order
+    ///
+    /// ## Events:
+    /// - `SettlementTransferredEvent` under `("SettlementTransferred", order_id, liquidity_provider)`, per settlement
+    fn transfer_settlements(
+        env: &Env,
+        order_id: &Bytes,
+        sequences: &Vec<u32>,
+    ) -> Result<(), ContractError> {
+        let order: Order = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Order(order_id.clone()))
+            .ok_or(ContractError::OrderNotFound)?;
+
+        // Temporary wallet must authorize the transfers
+        order.temporary_wallet_address.require_auth();
+
+        let storage = env.storage().persistent();
+        let mut pending_settlements = Vec::new(env);
+        for sequence in sequences.iter() {
+            let pending_settlement = LPContract::pending_settlement(env, order_id, sequence)
+                .ok_or(ContractError::NoPendingSettlement)?;
+            pending_settlements.push_back(pending_settlement);
+        }
+        if pending_settlements.is_empty() {
+            return Err(ContractError::NoPendingSettlement);
+        }
+
+        let settings_contract: Address = storage.get(&DataKey::SettingsContract).unwrap();
+        let settings_client = LPSettingManagerContractClient::new(env, &settings_contract);
+
+        if settings_client.is_operation_paused(&PauseOperation::SettlementTransfer, &order.token) {
+            return Err(ContractError::Paused);
+        }
+
+        if LPContract::order_frozen(env, &order.order_id) {
+            return Err(ContractError::OrderFrozen);
+        }
+
+        let token_client = token::Client::new(env, &order.token);
+        let mut remaining_sequences: Vec<u32> = storage
+            .get(&DataKey::PendingSettlementSequences(order_id.clone()))
+            .unwrap_or(Vec::new(env));
+
+        for pending_settlement in pending_settlements.iter() {
+            // Split protocol fee across the fee distribution
+            LPContract::distribute_fee(
+                env,
+                &settings_client,
+                &token_client,
+                &order,
+                pending_settlement.protocol_fee,
+            );
+
+            // Transfer remaining amount to liquidity provider
+            if pending_settlement.transfer_amount > 0 {
+                token_client.transfer(
+                    &order.temporary_wallet_address,
+                    &pending_settlement.liquidity_provider,
+                    &pending_settlement.transfer_amount,
+                );
+            }
+
+            // Clear pending settlement to prevent re-execution
+            storage.remove(&DataKey::SequencedSettlement(
+                order_id.clone(),
+                pending_settlement.sequence,
+            ));
+            if pending_settlement.sequence == 0 {
+                storage.remove(&DataKey::PendingSettlement(order_id.clone()));
+            }
+            if let Some(index) = remaining_sequences.first_index_of(pending_settlement.sequence) {
+                remaining_sequences.remove(index);
+            }
+
+            SettlementTransferredEvent {
+                version: SETTLEMENT_EVENT_VERSION,
+                order_id: order_id.clone(),
+                sequence: pending_settlement.sequence,
+                token: order.token.clone(),
+                recipient: pending_settlement.liquidity_provider,
+                settle_percent: pending_settlement.settle_percent,
+                gross_amount: pending_settlement.protocol_fee + pending_settlement.transfer_amount,
+                protocol_fee: pending_settlement.protocol_fee,
+                net_amount: pending_settlement.transfer_amount,
+                remaining_amount: pending_settlement.remaining_amount,
+                remaining_bps: pending_settlement.remaining_bps,
+            }
+            .publish(env);
+        }
+
+        if remaining_sequences.is_empty() {
+            storage.remove(&DataKey::PendingSettlementSequences(order_id.clone()));
+        } else {
+            storage.set(
+                &DataKey::PendingSettlementSequences(order_id.clone()),
+                &remaining_sequences,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// # Internal: Stage Refund
+    ///
+    /// ## Description:
+    /// Validates the order can be refunded, records the pending refund and marks the
+    /// order refunded. Shared by `refund`, `refund_expired` and `cancel_order`.
+    fn stage_refund(
+        env: &Env,
+        settings_client: &LPSettingManagerContractClient,
+        mut order: Order,
+        fee: i128,
+    ) -> Result<(), ContractError> {
+        if settings_client.is_operation_paused(&PauseOperation::Refund, &order.token) {
+            return Err(ContractError::Paused);
+        }
+
+        if LPContract::order_frozen(env, &order.order_id) {
+            return Err(ContractError::OrderFrozen);
+        }
+
+        // Prevent refund of fulfilled or already refunded orders
+        if order.is_fulfilled {
+            return Err(ContractError::OrderFulfilled);
+        }
+
+        if order.is_refunded {
+            return Err(ContractError::OrderRefunded);
+        }
+
+        let recipient = LPContract::refund_recipient(env, settings_client, &order)?;
+
+        // Validate refund fee doesn't exceed protocol fee
+        if fee > order.protocol_fee {
+            return Err(ContractError::FeeExceedsProtocolFee);
+        }
+
+        // Calculate and store pending refund
+        let pending_refund = PendingRefund {
+            order_id: order.order_id.clone(),
+            fee,
+            refund_amount: (order.amount) - fee,
+        };
+
+        env.storage().persistent().set(
+            &DataKey::PendingRefund(order.order_id.clone()),
+            &pending_refund,
+        );
+
+        // Update order state to refunded
+        order.is_refunded = true;
+        order.current_bps = 0;
+        order.amount = 0;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Order(order.order_id.clone()), &order);
+
+        OrderRefundedEvent {
+            version: REFUND_EVENT_VERSION,
+            order_id: order.order_id,
+            token: order.token,
+            recipient,
+            gross_amount: fee + pending_refund.refund_amount,
+            protocol_fee: fee,
+            net_amount: pending_refund.refund_amount,
+            remaining_amount: order.amount,
+            remaining_bps: order.current_bps,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// # Internal: Record Order Volume
+    ///
+    /// ## Description:
+    /// Adds `amount` to the rolling window tracked for `token` (global when `None`), if
+    /// the settings manager has a volume limit for it. The window is split into
+    /// `VOLUME_WINDOW_BUCKETS` buckets; a bucket counts until `window_seconds` after it
+    /// closes, so volume is never dropped early, at the cost of at most one bucket's
+    /// worth of extra retention. Reaching `auto_pause_at` trips the breaker for `token`.
+    ///
+    /// ## Returns:
+    /// - `Err(VolumeLimitExceeded)` if the window volume would exceed `max_volume`
+    fn record_volume(
+        env: &Env,
+        settings_client: &LPSettingManagerContractClient,
+        token: Option<Address>,
+        amount: i128,
+    ) -> Result<(), ContractError> {
+        let limit = match settings_client.get_volume_limit(&token) {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        let now = env.ledger().timestamp();
+        let bucket_seconds = limit.window_seconds.div_ceil(VOLUME_WINDOW_BUCKETS);
+        let bucket_ends_at = (now - now % bucket_seconds).saturating_add(bucket_seconds);
+
+        let key = DataKey::VolumeWindow(token.clone());
+        let stored: Option<VolumeWindow> = env.storage().persistent().get(&key);
+
+        // Drop buckets that closed more than a full window ago
+        let mut buckets: Vec<VolumeBucket> = Vec::new(env);
+        let mut volume: i128 = 0;
+        for bucket in stored
+            .map(|window| window.buckets)
+            .unwrap_or(Vec::new(env))
+            .iter()
+        {
+            if now < bucket.ends_at.saturating_add(limit.window_seconds) {
+                volume += bucket.volume;
+                buckets.push_back(bucket);
+            }
+        }
+
+        volume += amount;
+        if volume > limit.max_volume {
+            return Err(ContractError::VolumeLimitExceeded);
+        }
+
+        match buckets.last() {
+            Some(mut last) if last.ends_at == bucket_ends_at => {
+                last.volume += amount;
+                buckets.set(buckets.len() - 1, last);
+            }
+            _ => buckets.push_back(VolumeBucket {
+                ends_at: bucket_ends_at,
+                volume: amount,
+            }),
+        }
+
+        env.storage()
+            .persistent()
+            .set(&key, &VolumeWindow { volume, buckets });
+
+        if let Some(threshold) = limit.auto_pause_at {
+            if volume >= threshold {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::CircuitBreakerTripped(token.clone()), &true);
+                CircuitBreakerTrippedEvent {
+                    version: EVENT_VERSION,
+                    token,
+                    volume,
+                }
+                .publish(env);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Internal: Distribute Protocol Fee
+    ///
+    /// ## Description:
+    /// Transfers `fee` from the order's temporary wallet to each fee recipient by weight.
+    /// The last recipient receives any rounding remainder so the full fee is paid out.
+    ///
+    /// ## Events:
+    /// - `FeeDistributedEvent` under `("FeeDistributed", order_id, recipient)`, per non-zero share
+    fn distribute_fee(
+        env: &Env,
+        settings_client: &LPSettingManagerContractClient,
+        token_client: &token::Client,
+        order: &Order,
+        fee: i128,
+    ) {
+        if fee <= 0 {
+            return;
+        }
+
+        let recipients = settings_client.get_fee_distribution();
+        let last_index = recipients.len() - 1;
+        let mut remaining = fee;
+
+        for (index, share) in recipients.iter().enumerate() {
+            let amount = if index as u32 == last_index {
+                remaining
+            } else {
+                (fee * share.weight_bps as i128) / 100_000
+            };
+            remaining -= amount;
+
+            if amount > 0 {
+                token_client.transfer(&order.temporary_wallet_address, &share.recipient, &amount);
+                FeeDistributedEvent {
+                    version: EVENT_VERSION,
+                    order_id: order.order_id.clone(),
+                    recipient: share.recipient,
+                    token: order.token.clone(),
+                    amount,
+                }
+                .publish(env);
+            }
+        }
+    }
+
     /// # Register a new Liquidity Provider Node
     ///
     /// ## Description:
@@ -596,7 +1402,7 @@ impl LPContract {
     /// - LP node ID must not already exist
     ///
     /// ## Events:
-    /// - `("LpNodeRegistered", lp_node_id)` with capacity
+    /// - `LpNodeRegisteredEvent` under `("LpNodeRegistered", lp_node_id)`
     pub fn register_lp_node(
         env: Env,
         lp_node_id: Bytes,
@@ -621,12 +1427,399 @@ impl LPContract {
         node_ids.set(lp_node_id.clone(), true);
         env.storage().persistent().set(&DataKey::NodeIDs, &node_ids);
 
-        env.events()
-            .publish(("LpNodeRegistered", lp_node_id), capacity);
+        LpNodeRegisteredEvent {
+            version: EVENT_VERSION,
+            lp_node_id,
+            capacity,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// # Propose New LP Contract Admin
+    ///
+    /// ## Description:
+    /// Starts a two-step admin transfer. The proposed address only becomes admin
+    /// once it calls `accept_lp_admin`.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the proposal
+    ///
+    /// ## Validation:
+    /// - New admin must differ from the current admin
+    ///
+    /// ## Events:
+    /// - `AdminTransferProposedEvent` under `("AdminTransferProposed", admin)`
+    pub fn propose_lp_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if new_admin == admin {
+            return Err(ContractError::AddressAlreadySet);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingAdmin, &new_admin);
+        AdminTransferProposedEvent {
+            version: EVENT_VERSION,
+            admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Accept LP Contract Admin Role
+    ///
+    /// ## Description:
+    /// Completes a two-step admin transfer started by `propose_lp_admin`.
+    /// All stored orders are untouched.
+    ///
+    /// ## Authorization:
+    /// - Pending admin: Must authorize the acceptance
+    ///
+    /// ## Events:
+    /// - `AdminTransferredEvent` under `("AdminTransferred", previous_admin)`
+    pub fn accept_lp_admin(env: Env) -> Result<(), ContractError> {
+        let pending_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(ContractError::NoPendingAdmin)?;
+        pending_admin.require_auth();
+
+        let previous_admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
 
+        env.storage()
+            .persistent()
+            .set(&DataKey::Admin, &pending_admin);
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
+        AdminTransferredEvent {
+            version: EVENT_VERSION,
+            previous_admin,
+            new_admin: pending_admin,
+        }
+        .publish(&env);
         Ok(())
     }
 
+    /// # Cancel LP Contract Admin Transfer
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the cancellation
+    ///
+    /// ## Events:
+    /// - `AdminTransferCancelledEvent` under `("AdminTransferCancelled", admin)`
+    pub fn cancel_lp_admin_transfer(env: Env) -> Result<(), ContractError> {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let pending_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(ContractError::NoPendingAdmin)?;
+
+        env.storage().persistent().remove(&DataKey::PendingAdmin);
+        AdminTransferCancelledEvent {
+            version: EVENT_VERSION,
+            admin,
+            pending_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Freeze Order
+    ///
+    /// ## Description:
+    /// Places a compliance hold on a single order. While frozen, `settle`, `refund`
+    /// and both `execute_*_transfer` functions reject it with `OrderFrozen`.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Must hold the settings manager's Compliance role (or be its admin while
+    ///   no signers are registered) and authorize the hold
+    ///
+    /// ## Events:
+    /// - `OrderFrozenEvent` under `("OrderFrozen", order_id)`
+    pub fn freeze_order(
+        env: Env,
+        caller: Address,
+        order_id: Bytes,
+        reason: u32,
+    ) -> Result<(), ContractError> {
+        LPContract::compliance_order(&env, &caller, &order_id)?;
+        if LPContract::order_frozen(&env, &order_id) {
+            return Err(ContractError::OrderFrozen);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::OrderFrozen(order_id.clone()), &true);
+        OrderFrozenEvent {
+            version: EVENT_VERSION,
+            order_id,
+            reason,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Unfreeze Order
+    ///
+    /// ## Description:
+    /// Lifts the compliance hold placed by `freeze_order`.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Must hold the settings manager's Compliance role (or be its admin while
+    ///   no signers are registered) and authorize the release
+    ///
+    /// ## Events:
+    /// - `OrderUnfrozenEvent` under `("OrderUnfrozen", order_id)`
+    pub fn unfreeze_order(
+        env: Env,
+        caller: Address,
+        order_id: Bytes,
+        reason: u32,
+    ) -> Result<(), ContractError> {
+        LPContract::compliance_order(&env, &caller, &order_id)?;
+        if !LPContract::order_frozen(&env, &order_id) {
+            return Err(ContractError::OrderNotFrozen);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OrderFrozen(order_id.clone()));
+        OrderUnfrozenEvent {
+            version: EVENT_VERSION,
+            order_id,
+            reason,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Set Refund Destination
+    ///
+    /// ## Description:
+    /// Redirects an order's refund to `destination`. Lets compliance recover funds of
+    /// denylisted senders whose own `refund_address` cannot be approved, including
+    /// refunds already staged by `refund`, `refund_expired` or `cancel_order`.
+    /// A destination set here is used instead of `refund_address` and is not
+    /// screened again.
+    ///
+    /// ## Authorization:
+    /// - `caller`: Must hold the settings manager's Compliance role (or be its admin while
+    ///   no signers are registered) and authorize the change
+    ///
+    /// ## Validation:
+    /// - The order must not be fulfilled or have its refund already transferred
+    ///
+    /// ## Events:
+    /// - `RefundDestinationSetEvent` under `("RefundDestinationSet", order_id)`
+    pub fn set_refund_destination(
+        env: Env,
+        caller: Address,
+        order_id: Bytes,
+        destination: Address,
+    ) -> Result<(), ContractError> {
+        LPContract::compliance_order(&env, &caller, &order_id)?;
+
+        let order: Order = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Order(order_id.clone()))
+            .unwrap();
+        if order.is_fulfilled {
+            return Err(ContractError::OrderFulfilled);
+        }
+        if order.is_refunded
+            && !env
+                .storage()
+                .persistent()
+                .has(&DataKey::PendingRefund(order_id.clone()))
+        {
+            return Err(ContractError::OrderRefunded);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundDestination(order_id.clone()), &destination);
+        RefundDestinationSetEvent {
+            version: EVENT_VERSION,
+            order_id,
+            destination,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// # Get Refund Destination
+    ///
+    /// ## Returns:
+    /// - The compliance-set refund destination, or `None` when refunds go to `refund_address`
+    pub fn get_refund_destination(env: Env, order_id: Bytes) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundDestination(order_id))
+    }
+
+    /// # Internal: Refund Recipient
+    ///
+    /// ## Description:
+    /// Resolves where an order's refund is paid: the compliance-set destination if
+    /// any, otherwise `refund_address` subject to denylist screening.
+    ///
+    /// ## Returns:
+    /// - `Err(RefundAddressNotApproved)` if the sender is denylisted and `refund_address` is not approved
+    fn refund_recipient(
+        env: &Env,
+        settings_client: &LPSettingManagerContractClient,
+        order: &Order,
+    ) -> Result<Address, ContractError> {
+        if let Some(destination) = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RefundDestination(order.order_id.clone()))
+        {
+            return Ok(destination);
+        }
+
+        if !settings_client.is_refund_allowed(&order.sender, &order.refund_address) {
+            return Err(ContractError::RefundAddressNotApproved);
+        }
+        Ok(order.refund_address.clone())
+    }
+
+    /// # Is Order Frozen
+    ///
+    /// ## Returns:
+    /// - `true` while the order is under a compliance hold
+    pub fn is_order_frozen(env: Env, order_id: Bytes) -> bool {
+        LPContract::order_frozen(&env, &order_id)
+    }
+
+    /// # Internal: Order Frozen Flag
+    ///
+    /// ## Description:
+    /// The hold is kept under its own key so orders stored before it existed still decode.
+    fn order_frozen(env: &Env, order_id: &Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::OrderFrozen(order_id.clone()))
+    }
+
+    /// # Internal: Order Cancelled Flag
+    ///
+    /// ## Description:
+    /// Kept under its own key, like the compliance hold, so existing orders still decode.
+    fn order_cancelled(env: &Env, order_id: &Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::OrderCancelled(order_id.clone()))
+    }
+
+    /// # Internal: Check Compliance Action
+    ///
+    /// ## Description:
+    /// Authenticates `caller` and checks that the order exists. Follows the settings
+    /// manager's role rule: `caller` must hold the Compliance role, or be the manager's
+    /// admin while no signers are registered.
+    fn compliance_order(
+        env: &Env,
+        caller: &Address,
+        order_id: &Bytes,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let settings_contract: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::SettingsContract)
+            .unwrap();
+        let settings_client = LPSettingManagerContractClient::new(env, &settings_contract);
+        let admin_fallback =
+            *caller == settings_client.get_admin() && settings_client.get_signers().is_empty();
+        if !settings_client.has_role(&Role::Compliance, caller) && !admin_fallback {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Order(order_id.clone()))
+        {
+            return Err(ContractError::OrderNotFound);
+        }
+        Ok(())
+    }
+
+    /// # Reset Circuit Breaker
+    ///
+    /// ## Description:
+    /// Re-enables order creation for `token` (all tokens when `None`) after its volume
+    /// limit tripped the circuit breaker. Window usage is kept, so the underlying limit
+    /// still applies as volume rolls out of the window.
+    ///
+    /// ## Authorization:
+    /// - `admin`: Must authorize the reset
+    ///
+    /// ## Events:
+    /// - `CircuitBreakerResetEvent` under `("CircuitBreakerReset", admin)`
+    pub fn reset_circuit_breaker(env: Env, token: Option<Address>) {
+        let admin: Address = env.storage().persistent().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::CircuitBreakerTripped(token.clone()));
+        CircuitBreakerResetEvent {
+            version: EVENT_VERSION,
+            admin,
+            token,
+        }
+        .publish(&env);
+    }
+
+    /// # Is Circuit Breaker Tripped
+    ///
+    /// ## Returns:
+    /// - `true` if the volume limit for `token` (global when `None`) halted order creation
+    pub fn is_circuit_breaker_tripped(env: Env, token: Option<Address>) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CircuitBreakerTripped(token))
+            .unwrap_or(false)
+    }
+
+    /// # Get Volume Window
+    ///
+    /// ## Returns:
+    /// - Last recorded window for `token`, or the global window when `None`
+    pub fn get_volume_window(env: Env, token: Option<Address>) -> Option<VolumeWindow> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VolumeWindow(token))
+    }
+
+    /// # Get LP Contract Admin
+    ///
+    /// ## Returns:
+    /// - Current admin address
+    pub fn get_lp_admin(env: Env) -> Address {
+        env.storage().persistent().get(&DataKey::Admin).unwrap()
+    }
+
+    /// # Get Pending LP Contract Admin
+    ///
+    /// ## Returns:
+    /// - Address proposed via `propose_lp_admin`, if a transfer is outstanding
+    pub fn get_pending_lp_admin(env: Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::PendingAdmin)
+    }
+
     /// # Upgrade Contract WASM
     ///
     /// ## Description:
@@ -1,28 +1,67 @@
-use soroban_sdk::{Address, Bytes, Env};
+use soroban_sdk::{Address, Bytes, Env, Vec};
 
 use crate::{
     error::ContractError,
-    storage_types::{Order, OrderParams},
+    storage_types::{
+        Order, OrderParams, PendingSettlement, SettlementInstruction, SettlementResult,
+    },
 };
 
 pub trait IGateway {
     fn get_token_balance(env: Env, user: Address) -> i128;
     fn execute_settlement_transfer(env: Env, order_id: Bytes) -> Result<(), ContractError>;
+    fn execute_settlement_sequence(
+        env: Env,
+        order_id: Bytes,
+        sequence: u32,
+    ) -> Result<(), ContractError>;
     fn execute_refund_transfer(env: Env, order_id: Bytes) -> Result<(), ContractError>;
     fn create_order(env: Env, order_params: OrderParams) -> Result<(), ContractError>;
 
     fn settle(
         env: Env,
+        relayer: Address,
         order_id: Bytes,
         liquidity_provider: Address,
         settle_percent: i128,
     ) -> Result<bool, ContractError>;
 
+    fn batch_settle(
+        env: Env,
+        relayer: Address,
+        instructions: Vec<SettlementInstruction>,
+    ) -> Result<Vec<SettlementResult>, ContractError>;
+
+    fn settle_and_transfer(
+        env: Env,
+        relayer: Address,
+        order_id: Bytes,
+        liquidity_provider: Address,
+        settle_percent: i128,
+    ) -> Result<(), ContractError>;
+
     fn get_order_id(env: Env, order_id: Bytes) -> Result<Bytes, ContractError>;
 
-    fn refund(env: Env, order_id: Bytes, fee: i128) -> Result<(), ContractError>;
+    fn refund(env: Env, relayer: Address, order_id: Bytes, fee: i128) -> Result<(), ContractError>;
+
+    fn refund_and_transfer(
+        env: Env,
+        relayer: Address,
+        order_id: Bytes,
+        fee: i128,
+    ) -> Result<(), ContractError>;
+
+    fn refund_expired(env: Env, order_id: Bytes) -> Result<(), ContractError>;
+
+    fn cancel_order(env: Env, order_id: Bytes) -> Result<(), ContractError>;
 
     fn get_order_info(env: Env, order_id: Bytes) -> Result<Order, ContractError>;
 
+    fn get_order_expiry(env: Env, order_id: Bytes) -> Option<u64>;
+
+    fn is_order_cancelled(env: Env, order_id: Bytes) -> bool;
+
+    fn get_pending_settlements(env: Env, order_id: Bytes) -> Vec<PendingSettlement>;
+
     fn get_lp_fee_details(env: Env) -> (i64, i64);
 }
@@ -1,5 +1,5 @@
 use soroban_sdk::{contracttype, String};
-use soroban_sdk::{Address, Bytes};
+use soroban_sdk::{Address, Bytes, Vec};
 
 /// # Storage Data Keys
 ///
@@ -15,6 +15,8 @@ use soroban_sdk::{Address, Bytes};
 pub enum DataKey {
     /// Contract administrator address
     Admin,
+    /// Admin proposed via `propose_lp_admin`, awaiting acceptance
+    PendingAdmin,
     /// Address of the settings manager contract
     SettingsContract,
     /// Map of registered LP node IDs to their existence flags
@@ -23,12 +25,58 @@ pub enum DataKey {
     Nonces,
     /// Order data storage, keyed by order_id
     Order(Bytes),
-    /// USDC token contract address
+    /// Default token contract address (USDC), used by `get_token_balance`
     Usdc,
-    /// Pending settlement data, keyed by order_id
+    /// Pending settlement recorded before settlements were sequenced, read as sequence 0
     PendingSettlement(Bytes),
+    /// Pending settlement data, keyed by order_id and settlement sequence
+    SequencedSettlement(Bytes, u32),
+    /// Sequences of the order's settlements awaiting transfer, oldest first
+    PendingSettlementSequences(Bytes),
+    /// Sequence the order's next settlement will be recorded under
+    NextSettlementSequence(Bytes),
     /// Pending refund data, keyed by order_id
     PendingRefund(Bytes),
+    /// Present while the order is under a compliance hold
+    OrderFrozen(Bytes),
+    /// Ledger timestamp from which the order is expired; absent for orders created before expiry
+    OrderExpiry(Bytes),
+    /// Present once the sender has cancelled the order
+    OrderCancelled(Bytes),
+    /// Refund recipient set by compliance in place of the order's `refund_address`
+    RefundDestination(Bytes),
+    /// Order volume in the trailing window, per token or globally when `None`
+    VolumeWindow(Option<Address>),
+    /// Set when the token's volume limit (global when `None`) reaches its auto-pause threshold
+    CircuitBreakerTripped(Option<Address>),
+}
+
+/// # Volume Window
+///
+/// ## Description:
+/// Order volume recorded against a settings manager volume limit, kept as
+/// sub-window buckets so the limit applies to a rolling window.
+///
+/// ## Fields:
+/// - `volume`: Order volume in the trailing window as of the last recorded order
+/// - `buckets`: Buckets still overlapping the trailing window, oldest first
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VolumeWindow {
+    pub volume: i128,
+    pub buckets: Vec<VolumeBucket>,
+}
+
+/// # Volume Bucket
+///
+/// ## Fields:
+/// - `ends_at`: Ledger timestamp at which the bucket stops accepting volume
+/// - `volume`: Order volume recorded in the bucket
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VolumeBucket {
+    pub ends_at: u64,
+    pub volume: i128,
 }
 
 /// # Liquidity Provider Node
@@ -56,16 +104,38 @@ pub struct LpNode {
 /// - Temporary storage cleared after transfer execution
 /// - Prevents double-spending of settlement amounts
 /// - Ensures atomic transfer execution
+/// - Several may be outstanding per order, one for each `settle` call
 ///
 /// ## Fields:
 /// - `order_id`: Associated order identifier
+/// - `sequence`: Position of this settlement among the order's settlements, from 0
 /// - `protocol_fee`: Amount to send to treasury
 /// - `transfer_amount`: Amount to send to liquidity provider  
 /// - `liquidity_provider`: Recipient of settled funds
 /// - `settle_percent`: Percentage of order that was settled
+/// - `remaining_amount`: Unsettled order amount left once this settlement was recorded
+/// - `remaining_bps`: Unsettled basis points left once this settlement was recorded
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PendingSettlement {
+    pub order_id: Bytes,
+    pub sequence: u32,
+    pub protocol_fee: i128,
+    pub transfer_amount: i128,
+    pub liquidity_provider: Address,
+    pub settle_percent: i128,
+    pub remaining_amount: i128,
+    pub remaining_bps: i128,
+}
+
+/// # Legacy Pending Settlement Data
+///
+/// ## Description:
+/// Shape of a `PendingSettlement` stored under `DataKey::PendingSettlement` before
+/// settlements were sequenced. Only read, and treated as sequence 0.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyPendingSettlement {
     pub order_id: Bytes,
     pub protocol_fee: i128,
     pub transfer_amount: i128,
@@ -103,18 +173,22 @@ pub struct PendingRefund {
 /// ## Validation:
 /// - `order_id` must be unique
 /// - `amount` must be positive
+/// - `token` must be registered in the settings manager
 /// - `message_hash` must not be empty
+/// - `expires_at`, if set, must be in the future and within the settings manager's maximum order duration
 /// - All addresses must be valid
 ///
 /// ## Fields:
 /// - `order_id`: Unique identifier for the order (32 bytes recommended)
-/// - `token`: Token contract address (currently only USDC supported)
+/// - `token`: Token contract address (must be supported by the settings manager)
 /// - `sender`: Order creator who provides funds
 /// - `amount`: Order amount in token units
 /// - `rate`: Exchange rate for the order (implementation specific)
 /// - `temporary_wallet_address`: Non-custodial wallet holding order funds
 /// - `refund_address`: Recipient for refunds if order fails
 /// - `message_hash`: Cross-chain message identifier or order metadata
+/// - `expires_at`: Ledger timestamp from which the order can no longer be settled;
+///   defaults to the maximum order duration from now
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct OrderParams {
@@ -126,6 +200,41 @@ pub struct OrderParams {
     pub temporary_wallet_address: Address,
     pub refund_address: Address,
     pub message_hash: String,
+    pub expires_at: Option<u64>,
+}
+
+/// # Settlement Instruction
+///
+/// ## Description:
+/// One entry of a `batch_settle` call, with the same meaning as the matching `settle` arguments.
+///
+/// ## Fields:
+/// - `order_id`: Order to settle
+/// - `liquidity_provider`: Address to receive settled funds
+/// - `settle_percent`: Percentage to settle (in basis points, 100,000 = 100%)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementInstruction {
+    pub order_id: Bytes,
+    pub liquidity_provider: Address,
+    pub settle_percent: i128,
+}
+
+/// # Settlement Result
+///
+/// ## Description:
+/// Outcome of one `batch_settle` instruction, returned in instruction order.
+///
+/// ## Fields:
+/// - `order_id`: Order the instruction targeted
+/// - `sequence`: Pending settlement sequence when the instruction was applied
+/// - `error`: `ContractError` code when the instruction was rejected
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementResult {
+    pub order_id: Bytes,
+    pub sequence: Option<u32>,
+    pub error: Option<u32>,
 }
 
 /// # Order State
@@ -136,7 +245,9 @@ pub struct OrderParams {
 /// ## State Machine:
 /// - Created → [Partially Settled] → Fully Settled
 /// - Created → Refunded
+/// - Created → Cancelled (sender, before any settlement; staged as a refund)
 /// - States are mutually exclusive and one-way
+/// - Any state ⇄ Frozen (compliance hold, blocks settlement and refund)
 ///
 /// ## Note:
 /// - `is_fulfilled` and `is_refunded` are mutually exclusive
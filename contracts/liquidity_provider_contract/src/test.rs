@@ -1,9 +1,22 @@
+use crate::events::{
+    OrderCreatedEvent, OrderRefundedEvent, OrderSettledEvent, RefundTransferredEvent,
+    SettlementTransferredEvent, EVENT_VERSION, REFUND_EVENT_VERSION, SETTLEMENT_EVENT_VERSION,
+};
 use crate::liquidity_provider::{LPContract, LPContractClient};
-use crate::storage_types::OrderParams;
+use crate::storage_types::{
+    DataKey, LegacyPendingSettlement, OrderParams, PendingSettlement, SettlementInstruction,
+};
 use liquidity_manager::liquidity_manager::{
-    LPSettingManagerContract, LPSettingManagerContractClient,
+    LPSettingManagerContract, LPSettingManagerContractClient, MAX_ORDER_DURATION, MAX_VOLUME_WINDOW,
+};
+use liquidity_manager::storage::{
+    FeeRecipient, FeeTier, GovernanceAction, PauseOperation, PauseState, ProtocolAddressType,
+    ProtocolConfigUpdate, Role, VolumeLimit,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Bytes, Env, IntoVal, String, Symbol, TryFromVal,
 };
-use soroban_sdk::{testutils::Address as _, token, Address, Bytes, Env, String};
 
 fn create_token_contract<'a>(
     env: &Env,
@@ -18,6 +31,8 @@ fn create_token_contract<'a>(
 
 #[derive(Debug)]
 struct TestAddresses {
+    admin: Address,
+    relayer: Address,
     sender: Address,
     lp_node: Address,
     refund_address: Address,
@@ -49,6 +64,7 @@ fn setup<'a>() -> SetupResult<'a> {
     let lp_settings_contract_id = env.register(LPSettingManagerContract, ());
     let settings_client = LPSettingManagerContractClient::new(&env, &lp_settings_contract_id);
     settings_client.initialize(&admin, &treasury, &aggregator);
+    settings_client.add_supported_token(&usdc_asset);
 
     let lp_contract_id = env.register(LPContract, ());
     let lp_client = LPContractClient::new(&env, &lp_contract_id);
@@ -62,6 +78,8 @@ fn setup<'a>() -> SetupResult<'a> {
         usdc_asset,
         token_client,
         addresses: TestAddresses {
+            admin,
+            relayer: aggregator,
             sender,
             lp_node,
             refund_address,
@@ -70,6 +88,24 @@ fn setup<'a>() -> SetupResult<'a> {
     }
 }
 
+/// Sets a non-zero timelock delay, so timelocked setters must be queued
+fn enable_timelock(setup_result: &SetupResult, delay: u64) {
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateTimelockDelay(delay));
+    setup_result.settings_client.execute_action(&id);
+}
+
+/// Queues `action` and executes it once the timelock delay has elapsed
+fn queue_and_execute(setup_result: &SetupResult, action: GovernanceAction) {
+    let env = &setup_result.env;
+    let id = setup_result.settings_client.queue_action(&action);
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + setup_result.settings_client.get_timelock_delay(),
+    );
+    setup_result.settings_client.execute_action(&id);
+}
+
 #[test]
 fn test_register_lp_node() {
     let setup_result = setup();
@@ -100,6 +136,7 @@ fn test_create_order() {
         rate,
         refund_address: setup_result.addresses.refund_address.clone(),
         message_hash: message_hash.clone(),
+        expires_at: None,
     };
 
     setup_result
@@ -145,6 +182,7 @@ fn test_settle_full_order() {
         rate,
         refund_address: Address::generate(&setup_result.env),
         message_hash: message_hash.clone(),
+        expires_at: None,
     };
 
     setup_result.env.mock_all_auths();
@@ -165,6 +203,7 @@ fn test_settle_full_order() {
 
     setup_result.env.mock_all_auths();
     let result = setup_result.lp_client.try_settle(
+        &setup_result.addresses.relayer,
         &order_id,
         &setup_result.addresses.lp_node,
         &settle_percent,
@@ -229,6 +268,7 @@ fn test_refund_order() {
         rate,
         refund_address: setup_result.addresses.refund_address.clone(),
         message_hash: message_hash.clone(),
+        expires_at: None,
     };
 
     setup_result.env.mock_all_auths();
@@ -250,7 +290,10 @@ fn test_refund_order() {
     let refund_fee = 1_i128;
 
     setup_result.env.mock_all_auths();
-    let result = setup_result.lp_client.try_refund(&order_id, &refund_fee);
+    let result =
+        setup_result
+            .lp_client
+            .try_refund(&setup_result.addresses.relayer, &order_id, &refund_fee);
     assert!(
         result.is_ok(),
         "Refund initiation failed: {:?}",
@@ -303,7 +346,9 @@ fn test_pause_and_unpause_contract() {
 
     // Pause the contract
     setup_result.env.mock_all_auths();
-    let pause_result = setup_result.settings_client.try_pause();
+    let pause_result = setup_result
+        .settings_client
+        .try_pause(&setup_result.addresses.admin);
     assert!(pause_result.is_ok(), "Pause should succeed");
 
     // Verify contract is paused
@@ -312,7 +357,9 @@ fn test_pause_and_unpause_contract() {
 
     // Unpause the contract
     setup_result.env.mock_all_auths();
-    let unpause_result = setup_result.settings_client.try_unpause();
+    let unpause_result = setup_result
+        .settings_client
+        .try_unpause(&setup_result.addresses.admin);
     assert!(unpause_result.is_ok(), "Unpause should succeed");
 
     // Verify contract is unpaused
@@ -329,7 +376,9 @@ fn test_create_order_fails_when_paused() {
 
     // Pause the contract first
     setup_result.env.mock_all_auths();
-    setup_result.settings_client.pause();
+    setup_result
+        .settings_client
+        .pause(&setup_result.addresses.admin);
 
     let order_id = Bytes::from_array(&setup_result.env, &[5u8; 32]);
     let amount = 100_i128;
@@ -345,6 +394,7 @@ fn test_create_order_fails_when_paused() {
         rate,
         refund_address: setup_result.addresses.refund_address.clone(),
         message_hash: message_hash.clone(),
+        expires_at: None,
     };
 
     setup_result
@@ -386,6 +436,7 @@ fn test_settle_fails_when_paused() {
         rate: 9500_i64,
         refund_address: Address::generate(&setup_result.env),
         message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
     };
 
     setup_result.env.mock_all_auths();
@@ -393,11 +444,14 @@ fn test_settle_fails_when_paused() {
 
     // Now pause the contract
     setup_result.env.mock_all_auths();
-    setup_result.settings_client.pause();
+    setup_result
+        .settings_client
+        .pause(&setup_result.addresses.admin);
 
     // Attempt to settle while paused - should fail
     setup_result.env.mock_all_auths();
     let result = setup_result.lp_client.try_settle(
+        &setup_result.addresses.relayer,
         &order_id,
         &setup_result.addresses.lp_node,
         &100_000_i128, // 100%
@@ -434,6 +488,7 @@ fn test_refund_fails_when_paused() {
         rate: 9500_i64,
         refund_address: setup_result.addresses.refund_address.clone(),
         message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
     };
 
     setup_result.env.mock_all_auths();
@@ -441,11 +496,16 @@ fn test_refund_fails_when_paused() {
 
     // Now pause the contract
     setup_result.env.mock_all_auths();
-    setup_result.settings_client.pause();
+    setup_result
+        .settings_client
+        .pause(&setup_result.addresses.admin);
 
     // Attempt to refund while paused - should fail
     setup_result.env.mock_all_auths();
-    let result = setup_result.lp_client.try_refund(&order_id, &1_i128);
+    let result =
+        setup_result
+            .lp_client
+            .try_refund(&setup_result.addresses.relayer, &order_id, &1_i128);
 
     assert!(
         result.is_err(),
@@ -478,19 +538,25 @@ fn test_execute_transfers_work_when_paused() {
         rate: 9500_i64,
         refund_address: Address::generate(&setup_result.env),
         message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
     };
 
     setup_result.env.mock_all_auths();
     setup_result.lp_client.create_order(&order_params);
 
     setup_result.env.mock_all_auths();
-    setup_result
-        .lp_client
-        .settle(&order_id, &setup_result.addresses.lp_node, &100_000_i128);
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
 
     // Now pause the contract
     setup_result.env.mock_all_auths();
-    setup_result.settings_client.pause();
+    setup_result
+        .settings_client
+        .pause(&setup_result.addresses.admin);
 
     // Execute transfers should still work when paused
     setup_result.env.mock_all_auths();
@@ -515,15 +581,21 @@ fn test_only_admin_can_pause() {
     let setup_result = setup();
 
     // Try to pause with non-admin - should fail
-    Address::generate(&setup_result.env);
+    let non_admin = Address::generate(&setup_result.env);
     setup_result.env.mock_all_auths();
 
-    // This should fail because non_admin is not the admin
-    let result = setup_result.settings_client.try_pause();
-    assert!(result.is_ok());
+    // This should fail because non_admin is not the admin and holds no Pauser role
+    let result = setup_result.settings_client.try_pause(&non_admin);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+    assert!(!setup_result.settings_client.is_paused());
 
     setup_result.env.mock_all_auths();
-    let admin_pause_result = setup_result.settings_client.try_pause();
+    let admin_pause_result = setup_result
+        .settings_client
+        .try_pause(&setup_result.addresses.admin);
     assert!(admin_pause_result.is_ok(), "Admin should be able to pause");
 }
 
@@ -533,10 +605,14 @@ fn test_operations_resume_after_unpause() {
 
     // Pause and then unpause
     setup_result.env.mock_all_auths();
-    setup_result.settings_client.pause();
+    setup_result
+        .settings_client
+        .pause(&setup_result.addresses.admin);
 
     setup_result.env.mock_all_auths();
-    setup_result.settings_client.unpause();
+    setup_result
+        .settings_client
+        .unpause(&setup_result.addresses.admin);
 
     // Verify we can create orders again after unpause
     let order_id = Bytes::from_array(&setup_result.env, &[9u8; 32]);
@@ -555,6 +631,7 @@ fn test_operations_resume_after_unpause() {
         rate: 9500_i64,
         refund_address: setup_result.addresses.refund_address.clone(),
         message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
     };
 
     setup_result.env.mock_all_auths();
@@ -565,3 +642,3234 @@ fn test_operations_resume_after_unpause() {
         "Should be able to create order after unpause"
     );
 }
+
+#[test]
+fn test_supported_token_registry() {
+    let setup_result = setup();
+    let eurc_asset = Address::generate(&setup_result.env);
+
+    let tokens = setup_result.settings_client.get_supported_tokens();
+    assert_eq!(tokens.len(), 1);
+    assert!(setup_result
+        .settings_client
+        .is_token_supported(&setup_result.usdc_asset));
+
+    setup_result
+        .settings_client
+        .add_supported_token(&eurc_asset);
+    assert!(setup_result.settings_client.is_token_supported(&eurc_asset));
+    assert_eq!(setup_result.settings_client.get_supported_tokens().len(), 2);
+
+    let duplicate = setup_result
+        .settings_client
+        .try_add_supported_token(&eurc_asset);
+    assert_eq!(
+        duplicate.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TokenAlreadySupported
+    );
+
+    setup_result
+        .settings_client
+        .remove_supported_token(&setup_result.usdc_asset);
+    assert!(!setup_result
+        .settings_client
+        .is_token_supported(&setup_result.usdc_asset));
+    let tokens = setup_result.settings_client.get_supported_tokens();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens.get(0).unwrap(), eurc_asset);
+
+    let missing = setup_result
+        .settings_client
+        .try_remove_supported_token(&setup_result.usdc_asset);
+    assert_eq!(
+        missing.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TokenNotSupported
+    );
+}
+
+#[test]
+fn test_create_order_fails_for_unsupported_token() {
+    let setup_result = setup();
+    let admin = Address::generate(&setup_result.env);
+    let (eurc_asset, eurc_client) = create_token_contract(&setup_result.env, &admin);
+    let amount = 100_i128;
+
+    eurc_client.mint(&setup_result.addresses.sender, &amount);
+
+    let order_params = OrderParams {
+        order_id: Bytes::from_array(&setup_result.env, &[10u8; 32]),
+        token: eurc_asset,
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+
+    let result = setup_result.lp_client.try_create_order(&order_params);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::TokenNotSupported
+    );
+}
+
+#[test]
+fn test_settle_order_in_secondary_token() {
+    let setup_result = setup();
+    let admin = Address::generate(&setup_result.env);
+    let (eurc_asset, eurc_client) = create_token_contract(&setup_result.env, &admin);
+    let eurc_balance = token::TokenClient::new(&setup_result.env, &eurc_asset);
+    let amount = 100_i128;
+
+    setup_result
+        .settings_client
+        .add_supported_token(&eurc_asset);
+    eurc_client.mint(&setup_result.addresses.sender, &amount);
+
+    let order_id = Bytes::from_array(&setup_result.env, &[11u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: eurc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+
+    setup_result.lp_client.create_order(&order_params);
+
+    let order = setup_result.lp_client.get_order_info(&order_id);
+    assert_eq!(order.token, eurc_asset);
+    assert_eq!(
+        eurc_balance.balance(&setup_result.addresses.temporary_wallet),
+        amount
+    );
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+
+    assert_eq!(eurc_balance.balance(&setup_result.addresses.lp_node), 99);
+    assert_eq!(
+        eurc_balance.balance(&setup_result.addresses.temporary_wallet),
+        0
+    );
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.lp_node),
+        0,
+        "USDC balance should be untouched"
+    );
+}
+
+#[test]
+fn test_per_token_fee_overrides_global_fee() {
+    let setup_result = setup();
+    let amount = 100_i128;
+
+    // 2% for USDC, global stays at 1%
+    setup_result.settings_client.set_token_fee(
+        &setup_result.addresses.admin,
+        &setup_result.usdc_asset,
+        &2000_i64,
+    );
+    assert_eq!(
+        setup_result
+            .settings_client
+            .get_token_fee_details(&setup_result.usdc_asset),
+        (2000, 100_000)
+    );
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (1000, 100_000)
+    );
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+
+    let order_id = Bytes::from_array(&setup_result.env, &[12u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+
+    setup_result.lp_client.create_order(&order_params);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_order_info(&order_id)
+            .protocol_fee,
+        2
+    );
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.lp_node),
+        98
+    );
+
+    setup_result
+        .settings_client
+        .remove_token_fee(&setup_result.addresses.admin, &setup_result.usdc_asset);
+    assert_eq!(
+        setup_result
+            .settings_client
+            .get_token_fee_details(&setup_result.usdc_asset),
+        (1000, 100_000)
+    );
+
+    let invalid = setup_result.settings_client.try_set_token_fee(
+        &setup_result.addresses.admin,
+        &setup_result.usdc_asset,
+        &100_001_i64,
+    );
+    assert_eq!(
+        invalid.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeePercent
+    );
+}
+
+#[test]
+fn test_settings_admin_two_step_transfer() {
+    let setup_result = setup();
+    let old_admin = setup_result.addresses.admin.clone();
+    let new_admin = Address::generate(&setup_result.env);
+
+    assert_eq!(setup_result.settings_client.get_admin(), old_admin);
+    assert_eq!(setup_result.settings_client.get_pending_admin(), None);
+
+    let no_pending = setup_result.settings_client.try_accept_admin();
+    assert_eq!(
+        no_pending.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::NoPendingAdmin
+    );
+
+    setup_result.settings_client.propose_admin(&new_admin);
+    assert_eq!(
+        setup_result.settings_client.get_pending_admin(),
+        Some(new_admin.clone())
+    );
+    // Proposal alone does not move the admin role
+    assert_eq!(setup_result.settings_client.get_admin(), old_admin);
+
+    setup_result.settings_client.cancel_admin_transfer();
+    assert_eq!(setup_result.settings_client.get_pending_admin(), None);
+
+    setup_result.settings_client.propose_admin(&new_admin);
+    setup_result.settings_client.accept_admin();
+
+    let auths = setup_result.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, new_admin);
+
+    assert_eq!(setup_result.settings_client.get_admin(), new_admin);
+    assert_eq!(setup_result.settings_client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_lp_admin_two_step_transfer() {
+    let setup_result = setup();
+    let old_admin = setup_result.addresses.admin.clone();
+    let new_admin = Address::generate(&setup_result.env);
+
+    assert_eq!(setup_result.lp_client.get_lp_admin(), old_admin);
+
+    let same_admin = setup_result.lp_client.try_propose_lp_admin(&old_admin);
+    assert_eq!(
+        same_admin.err().unwrap().unwrap(),
+        crate::error::ContractError::AddressAlreadySet
+    );
+
+    let nothing_to_cancel = setup_result.lp_client.try_cancel_lp_admin_transfer();
+    assert_eq!(
+        nothing_to_cancel.err().unwrap().unwrap(),
+        crate::error::ContractError::NoPendingAdmin
+    );
+
+    setup_result.lp_client.propose_lp_admin(&new_admin);
+    assert_eq!(
+        setup_result.lp_client.get_pending_lp_admin(),
+        Some(new_admin.clone())
+    );
+
+    setup_result.lp_client.accept_lp_admin();
+    assert_eq!(setup_result.env.auths()[0].0, new_admin);
+    assert_eq!(setup_result.lp_client.get_lp_admin(), new_admin);
+    assert_eq!(setup_result.lp_client.get_pending_lp_admin(), None);
+}
+
+#[test]
+fn test_role_grant_revoke_and_renounce() {
+    let setup_result = setup();
+    let pauser = Address::generate(&setup_result.env);
+    let fee_manager = Address::generate(&setup_result.env);
+
+    setup_result
+        .settings_client
+        .grant_role(&Role::Pauser, &pauser);
+    setup_result
+        .settings_client
+        .grant_role(&Role::FeeManager, &fee_manager);
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Pauser, &pauser));
+
+    let duplicate = setup_result
+        .settings_client
+        .try_grant_role(&Role::Pauser, &pauser);
+    assert_eq!(
+        duplicate.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::RoleAlreadyGranted
+    );
+
+    // Pauser can pause, but cannot touch fees
+    setup_result.settings_client.pause(&pauser);
+    assert!(setup_result.settings_client.is_paused());
+    let fee_result = setup_result
+        .settings_client
+        .try_update_protocol_fee(&pauser, &500_i64);
+    assert_eq!(
+        fee_result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+
+    setup_result
+        .settings_client
+        .update_protocol_fee(&fee_manager, &500_i64);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (500, 100_000)
+    );
+
+    setup_result
+        .settings_client
+        .revoke_role(&Role::Pauser, &pauser);
+    let unpause_result = setup_result.settings_client.try_unpause(&pauser);
+    assert_eq!(
+        unpause_result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+
+    setup_result
+        .settings_client
+        .renounce_role(&Role::FeeManager, &fee_manager);
+    assert!(setup_result
+        .settings_client
+        .get_role_members(&Role::FeeManager)
+        .is_empty());
+
+    let not_granted = setup_result
+        .settings_client
+        .try_renounce_role(&Role::FeeManager, &fee_manager);
+    assert_eq!(
+        not_granted.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::RoleNotGranted
+    );
+}
+
+#[test]
+fn test_settle_requires_relayer_role() {
+    let setup_result = setup();
+    let second_relayer = Address::generate(&setup_result.env);
+    let outsider = Address::generate(&setup_result.env);
+    let amount = 100_i128;
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+
+    let order_id = Bytes::from_array(&setup_result.env, &[13u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    let result = setup_result.lp_client.try_settle(
+        &outsider,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &50_000_i128,
+    );
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Unauthorized
+    );
+
+    // Two relayer instances can settle the same order
+    setup_result
+        .settings_client
+        .grant_role(&Role::Relayer, &second_relayer);
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &50_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    setup_result.lp_client.settle(
+        &second_relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &50_000_i128,
+    );
+    assert!(
+        setup_result
+            .lp_client
+            .get_order_info(&order_id)
+            .is_fulfilled
+    );
+
+    // Rotating the primary relayer moves its role
+    setup_result
+        .settings_client
+        .update_protocol_address(&ProtocolAddressType::Aggregator, &outsider);
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &outsider));
+    assert!(!setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &setup_result.addresses.relayer));
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &second_relayer));
+
+    // The primary relayer's role cannot be dropped while it is primary
+    let result = setup_result
+        .settings_client
+        .try_revoke_role(&Role::Relayer, &outsider);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::PrimaryRelayer
+    );
+    let result = setup_result
+        .settings_client
+        .try_renounce_role(&Role::Relayer, &outsider);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::PrimaryRelayer
+    );
+    assert_eq!(setup_result.settings_client.get_relayer_address(), outsider);
+    assert!(setup_result
+        .settings_client
+        .is_relayer_authorized(&outsider));
+}
+
+#[test]
+fn test_timelocked_fee_change() {
+    let setup_result = setup();
+    let delay = 86_400_u64;
+
+    // With no delay configured the delay update itself executes immediately
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateTimelockDelay(delay));
+    setup_result.settings_client.execute_action(&id);
+    assert_eq!(setup_result.settings_client.get_timelock_delay(), delay);
+
+    let too_long = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::UpdateTimelockDelay(u64::MAX));
+    assert_eq!(
+        too_long.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidParameter
+    );
+
+    // Direct updates are now blocked
+    let direct = setup_result
+        .settings_client
+        .try_update_protocol_fee(&setup_result.addresses.admin, &2000_i64);
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+
+    let now = setup_result.env.ledger().timestamp();
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateProtocolFee(2000));
+    let queued = setup_result.settings_client.get_queued_action(&id).unwrap();
+    assert_eq!(queued.eta, now + delay);
+
+    let early = setup_result.settings_client.try_execute_action(&id);
+    assert_eq!(
+        early.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockNotElapsed
+    );
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (1000, 100_000)
+    );
+
+    setup_result.env.ledger().set_timestamp(now + delay);
+    setup_result.settings_client.execute_action(&id);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (2000, 100_000)
+    );
+    assert_eq!(setup_result.settings_client.get_queued_action(&id), None);
+
+    // Per-token fees are timelocked the same way
+    let usdc = &setup_result.usdc_asset;
+    let direct = setup_result.settings_client.try_set_token_fee(
+        &setup_result.addresses.admin,
+        usdc,
+        &100_000_i64,
+    );
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+    let now = setup_result.env.ledger().timestamp();
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::SetTokenFee(usdc.clone(), 2500));
+    setup_result.env.ledger().set_timestamp(now + delay);
+    setup_result.settings_client.execute_action(&id);
+    assert_eq!(
+        setup_result.settings_client.get_token_fee_details(usdc),
+        (2500, 100_000)
+    );
+    let direct = setup_result
+        .settings_client
+        .try_remove_token_fee(&setup_result.addresses.admin, usdc);
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+
+    let replay = setup_result.settings_client.try_execute_action(&id);
+    assert_eq!(
+        replay.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::ActionNotFound
+    );
+}
+
+#[test]
+fn test_fee_schedule_changes_are_timelocked() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    enable_timelock(&setup_result, 3_600);
+
+    let tiers = vec![
+        env,
+        FeeTier {
+            min_amount: 0,
+            max_amount: 1_000_000,
+            fee_percent: 500,
+        },
+    ];
+    let direct = setup_result
+        .settings_client
+        .try_set_fee_tiers(admin, &tiers);
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+    queue_and_execute(&setup_result, GovernanceAction::SetFeeTiers(tiers.clone()));
+    assert_eq!(setup_result.settings_client.get_fee_tiers(), tiers);
+}
+
+#[test]
+fn test_cancellation_fee_changes_are_timelocked() {
+    let setup_result = setup();
+    let admin = &setup_result.addresses.admin;
+    enable_timelock(&setup_result, 3_600);
+
+    let direct = setup_result
+        .settings_client
+        .try_set_cancellation_fee(admin, &250);
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+    queue_and_execute(&setup_result, GovernanceAction::SetCancellationFee(250));
+    assert_eq!(setup_result.settings_client.get_cancellation_fee(), 250);
+}
+
+#[test]
+fn test_lp_fee_override_changes_are_timelocked() {
+    let setup_result = setup();
+    let admin = &setup_result.addresses.admin;
+    enable_timelock(&setup_result, 3_600);
+
+    let lp_node = &setup_result.addresses.lp_node;
+    let direct = setup_result
+        .settings_client
+        .try_set_lp_fee_override(admin, lp_node, &100, &None);
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+    let invalid =
+        setup_result
+            .settings_client
+            .try_queue_action(&GovernanceAction::SetLpFeeOverride(
+                lp_node.clone(),
+                100_001,
+                None,
+            ));
+    assert_eq!(
+        invalid.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeePercent
+    );
+    queue_and_execute(
+        &setup_result,
+        GovernanceAction::SetLpFeeOverride(lp_node.clone(), 100, None),
+    );
+    assert_eq!(
+        setup_result.settings_client.get_active_lp_fee(lp_node),
+        Some(100)
+    );
+    queue_and_execute(
+        &setup_result,
+        GovernanceAction::RemoveLpFeeOverride(lp_node.clone()),
+    );
+    assert_eq!(
+        setup_result.settings_client.get_active_lp_fee(lp_node),
+        None
+    );
+}
+
+#[test]
+fn test_token_fee_bound_changes_are_timelocked() {
+    let setup_result = setup();
+    let admin = &setup_result.addresses.admin;
+    enable_timelock(&setup_result, 3_600);
+
+    let token = &setup_result.usdc_asset;
+    let direct = setup_result
+        .settings_client
+        .try_set_token_fee_bounds(admin, token, &10, &1_000);
+    assert_eq!(
+        direct.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+    let invalid = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::SetTokenFeeBounds(token.clone(), 10, 5));
+    assert_eq!(
+        invalid.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidParameter
+    );
+    queue_and_execute(
+        &setup_result,
+        GovernanceAction::SetTokenFeeBounds(token.clone(), 10, 1_000),
+    );
+    let bounds = setup_result
+        .settings_client
+        .get_token_fee_bounds(token)
+        .unwrap();
+    assert_eq!((bounds.min_fee, bounds.max_fee), (10, 1_000));
+    queue_and_execute(
+        &setup_result,
+        GovernanceAction::RemoveTokenFeeBounds(token.clone()),
+    );
+    assert!(setup_result
+        .settings_client
+        .get_token_fee_bounds(token)
+        .is_none());
+}
+
+#[test]
+fn test_queued_fee_schedule_is_validated() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let treasury = setup_result.settings_client.get_treasury_address();
+
+    // Overlapping tiers are rejected when queued, not when executed
+    let overlapping = vec![
+        env,
+        FeeTier {
+            min_amount: 0,
+            max_amount: 1_000_000,
+            fee_percent: 500,
+        },
+        FeeTier {
+            min_amount: 500_000,
+            max_amount: 2_000_000,
+            fee_percent: 300,
+        },
+    ];
+    let result = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::SetFeeTiers(overlapping));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeeTiers
+    );
+
+    let partial = vec![
+        env,
+        FeeRecipient {
+            recipient: treasury,
+            weight_bps: 70_000,
+        },
+    ];
+    let result = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::UpdateFeeDistribution(partial));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeeDistribution
+    );
+}
+
+#[test]
+fn test_cancel_timelocked_action() {
+    let setup_result = setup();
+    let new_treasury = Address::generate(&setup_result.env);
+    let old_treasury = setup_result.settings_client.get_treasury_address();
+
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateTimelockDelay(3_600));
+    setup_result.settings_client.execute_action(&id);
+
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateProtocolAddress(
+            ProtocolAddressType::Treasury,
+            new_treasury,
+        ));
+    setup_result.settings_client.cancel_action(&id);
+
+    setup_result
+        .env
+        .ledger()
+        .set_timestamp(setup_result.env.ledger().timestamp() + 3_600);
+    let result = setup_result.settings_client.try_execute_action(&id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::ActionNotFound
+    );
+    assert_eq!(
+        setup_result.settings_client.get_treasury_address(),
+        old_treasury
+    );
+}
+
+#[test]
+fn test_multisig_signers_approve_and_execute() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let signer_a = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let signer_c = Address::generate(env);
+    let outsider = Address::generate(env);
+
+    let invalid = setup_result
+        .settings_client
+        .try_set_signers(&vec![env, signer_a.clone()], &2_u32);
+    assert_eq!(
+        invalid.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidThreshold
+    );
+
+    setup_result.settings_client.set_signers(
+        &vec![env, signer_a.clone(), signer_b.clone(), signer_c.clone()],
+        &2_u32,
+    );
+    assert_eq!(setup_result.settings_client.get_signer_threshold(), 2);
+
+    // The admin cannot replace a configured signer set on its own
+    let reconfigure = setup_result
+        .settings_client
+        .try_set_signers(&vec![env, outsider.clone()], &1_u32);
+    assert_eq!(
+        reconfigure.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::SignerSetAlreadyConfigured
+    );
+
+    let not_signer = setup_result
+        .settings_client
+        .try_propose(&outsider, &GovernanceAction::Pause);
+    assert_eq!(
+        not_signer.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::NotSigner
+    );
+
+    let id = setup_result
+        .settings_client
+        .propose(&signer_a, &GovernanceAction::UpdateProtocolFee(500));
+    let early = setup_result.settings_client.try_execute_proposal(&id);
+    assert_eq!(
+        early.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::ThresholdNotReached
+    );
+
+    let duplicate = setup_result
+        .settings_client
+        .try_approve_proposal(&signer_a, &id);
+    assert_eq!(
+        duplicate.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::AlreadyApproved
+    );
+
+    setup_result
+        .settings_client
+        .approve_proposal(&signer_b, &id);
+    setup_result.settings_client.execute_proposal(&id);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (500, 100_000)
+    );
+    assert_eq!(setup_result.settings_client.get_proposal(&id), None);
+
+    let id = setup_result
+        .settings_client
+        .propose(&signer_c, &GovernanceAction::Pause);
+    setup_result
+        .settings_client
+        .approve_proposal(&signer_a, &id);
+    setup_result.settings_client.execute_proposal(&id);
+    assert!(setup_result.settings_client.is_paused());
+}
+
+#[test]
+fn test_multisig_signer_set_change_requires_approval() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let signer_a = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let signer_c = Address::generate(env);
+
+    setup_result
+        .settings_client
+        .set_signers(&vec![env, signer_a.clone(), signer_b.clone()], &2_u32);
+
+    // Admin may not route signer changes through the timelock queue
+    let queued = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::UpdateSignerSet(
+            vec![env, signer_c.clone()],
+            1,
+        ));
+    assert_eq!(
+        queued.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+
+    let stale = setup_result
+        .settings_client
+        .propose(&signer_b, &GovernanceAction::Pause);
+
+    let id = setup_result.settings_client.propose(
+        &signer_a,
+        &GovernanceAction::UpdateSignerSet(vec![env, signer_a.clone(), signer_c.clone()], 2),
+    );
+    setup_result
+        .settings_client
+        .approve_proposal(&signer_b, &id);
+    setup_result.settings_client.execute_proposal(&id);
+
+    assert_eq!(
+        setup_result.settings_client.get_signers(),
+        vec![env, signer_a.clone(), signer_c.clone()]
+    );
+
+    // signer_b's earlier approval no longer counts once it has been removed
+    setup_result
+        .settings_client
+        .approve_proposal(&signer_a, &stale);
+    let result = setup_result.settings_client.try_execute_proposal(&stale);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::ThresholdNotReached
+    );
+    setup_result
+        .settings_client
+        .approve_proposal(&signer_c, &stale);
+    setup_result.settings_client.execute_proposal(&stale);
+    assert!(setup_result.settings_client.is_paused());
+}
+
+#[test]
+fn test_signers_replace_admin_authority() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let signer_a = Address::generate(env);
+    let signer_b = Address::generate(env);
+    let fee_manager = Address::generate(env);
+
+    setup_result
+        .settings_client
+        .set_signers(&vec![env, signer_a.clone(), signer_b.clone()], &2_u32);
+
+    // The admin key alone no longer changes settings
+    let fee = setup_result
+        .settings_client
+        .try_update_protocol_fee(admin, &500_i64);
+    assert_eq!(
+        fee.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::SignerApprovalRequired
+    );
+    let grant = setup_result
+        .settings_client
+        .try_grant_role(&Role::FeeManager, &fee_manager);
+    assert_eq!(
+        grant.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::SignerApprovalRequired
+    );
+    let queued = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::Pause);
+    assert_eq!(
+        queued.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::SignerApprovalRequired
+    );
+
+    let approve_and_execute = |action: GovernanceAction| {
+        let id = setup_result.settings_client.propose(&signer_a, &action);
+        setup_result
+            .settings_client
+            .approve_proposal(&signer_b, &id);
+        setup_result.settings_client.execute_proposal(&id);
+    };
+
+    // Role holders the signers appoint keep working
+    approve_and_execute(GovernanceAction::GrantRole(
+        Role::FeeManager,
+        fee_manager.clone(),
+    ));
+    setup_result
+        .settings_client
+        .update_protocol_fee(&fee_manager, &500_i64);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (500, 100_000)
+    );
+
+    // Signer-approved changes enter the queue and only the signers can cancel them
+    approve_and_execute(GovernanceAction::UpdateTimelockDelay(3_600));
+    approve_and_execute(GovernanceAction::UpdateProtocolFee(700));
+    let queued_id = 0_u64;
+    assert!(setup_result
+        .settings_client
+        .get_queued_action(&queued_id)
+        .is_some());
+    let veto = setup_result.settings_client.try_cancel_action(&queued_id);
+    assert_eq!(
+        veto.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::SignerApprovalRequired
+    );
+    approve_and_execute(GovernanceAction::CancelAction(queued_id));
+    assert_eq!(
+        setup_result.settings_client.get_queued_action(&queued_id),
+        None
+    );
+
+    // Only the proposer can withdraw a proposal
+    let id = setup_result
+        .settings_client
+        .propose(&signer_a, &GovernanceAction::Pause);
+    let result = setup_result
+        .settings_client
+        .try_cancel_proposal(&signer_b, &id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+    setup_result.settings_client.cancel_proposal(&signer_a, &id);
+    assert_eq!(setup_result.settings_client.get_proposal(&id), None);
+
+    // Stale proposals expire
+    let id = setup_result
+        .settings_client
+        .propose(&signer_a, &GovernanceAction::Pause);
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + liquidity_manager::liquidity_manager::PROPOSAL_LIFETIME,
+    );
+    let result = setup_result
+        .settings_client
+        .try_approve_proposal(&signer_b, &id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::ProposalExpired
+    );
+    let result = setup_result.settings_client.try_execute_proposal(&id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::ProposalExpired
+    );
+    assert!(!setup_result.settings_client.is_paused());
+}
+
+#[test]
+fn test_granular_operation_pause() {
+    let setup_result = setup();
+    let admin = &setup_result.addresses.admin;
+    let amount = 100_i128;
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &(amount * 2));
+
+    let order_id = Bytes::from_array(&setup_result.env, &[14u8; 32]);
+    let mut order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    // Stop new orders but keep refunds flowing
+    setup_result
+        .settings_client
+        .set_operation_paused(admin, &PauseOperation::CreateOrder, &true);
+    assert_eq!(
+        setup_result.settings_client.get_pause_state(),
+        PauseState {
+            global: false,
+            create_order: true,
+            settle: false,
+            refund: false,
+            settlement_transfer: false,
+            refund_transfer: false,
+        }
+    );
+
+    order_params.order_id = Bytes::from_array(&setup_result.env, &[15u8; 32]);
+    let result = setup_result.lp_client.try_create_order(&order_params);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Paused
+    );
+
+    setup_result
+        .lp_client
+        .refund(&setup_result.addresses.relayer, &order_id, &1_i128);
+
+    setup_result.settings_client.set_operation_paused(
+        admin,
+        &PauseOperation::RefundTransfer,
+        &true,
+    );
+    let result = setup_result
+        .lp_client
+        .try_execute_refund_transfer(&order_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Paused
+    );
+
+    setup_result.settings_client.set_operation_paused(
+        admin,
+        &PauseOperation::RefundTransfer,
+        &false,
+    );
+    setup_result.lp_client.execute_refund_transfer(&order_id);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.refund_address),
+        99
+    );
+}
+
+#[test]
+fn test_token_pause_freezes_single_asset() {
+    let setup_result = setup();
+    let admin = Address::generate(&setup_result.env);
+    let (eurc_asset, eurc_client) = create_token_contract(&setup_result.env, &admin);
+    let amount = 100_i128;
+
+    setup_result
+        .settings_client
+        .add_supported_token(&eurc_asset);
+    eurc_client.mint(&setup_result.addresses.sender, &amount);
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+
+    let eurc_order_id = Bytes::from_array(&setup_result.env, &[16u8; 32]);
+    let mut order_params = OrderParams {
+        order_id: eurc_order_id.clone(),
+        token: eurc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    setup_result.settings_client.set_token_paused(
+        &setup_result.addresses.admin,
+        &eurc_asset,
+        &true,
+    );
+    assert!(setup_result.settings_client.is_token_paused(&eurc_asset));
+
+    let result = setup_result.lp_client.try_settle(
+        &setup_result.addresses.relayer,
+        &eurc_order_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Paused
+    );
+
+    // USDC orders are unaffected
+    order_params.order_id = Bytes::from_array(&setup_result.env, &[17u8; 32]);
+    order_params.token = setup_result.usdc_asset.clone();
+    let result = setup_result.lp_client.try_create_order(&order_params);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_scheduled_fee_takes_effect_at_timestamp() {
+    let setup_result = setup();
+    let admin = &setup_result.addresses.admin;
+    let now = setup_result.env.ledger().timestamp();
+
+    let past = setup_result
+        .settings_client
+        .try_schedule_protocol_fee(admin, &2000_i64, &now);
+    assert_eq!(
+        past.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidParameter
+    );
+
+    setup_result
+        .settings_client
+        .schedule_protocol_fee(admin, &3000_i64, &(now + 200));
+    setup_result
+        .settings_client
+        .schedule_protocol_fee(admin, &2000_i64, &(now + 100));
+
+    let upcoming = setup_result.settings_client.get_scheduled_fees();
+    assert_eq!(upcoming.len(), 2);
+    assert_eq!(upcoming.get(0).unwrap().effective_at, now + 100);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (1000, 100_000)
+    );
+
+    setup_result.env.ledger().set_timestamp(now + 100);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (2000, 100_000)
+    );
+    assert_eq!(setup_result.settings_client.get_scheduled_fees().len(), 1);
+
+    let amount = 100_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(&setup_result.env, &[18u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(&setup_result.env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_order_info(&order_id)
+            .protocol_fee,
+        2
+    );
+
+    setup_result
+        .settings_client
+        .cancel_scheduled_fee(admin, &(now + 200));
+    assert!(setup_result.settings_client.get_scheduled_fees().is_empty());
+
+    setup_result.env.ledger().set_timestamp(now + 300);
+    assert_eq!(
+        setup_result.settings_client.get_fee_details(),
+        (2000, 100_000)
+    );
+}
+
+#[test]
+fn test_volume_fee_tiers() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+
+    let overlapping = setup_result.settings_client.try_set_fee_tiers(
+        admin,
+        &vec![
+            env,
+            FeeTier {
+                min_amount: 0,
+                max_amount: 1_000,
+                fee_percent: 2000,
+            },
+            FeeTier {
+                min_amount: 500,
+                max_amount: i128::MAX,
+                fee_percent: 500,
+            },
+        ],
+    );
+    assert_eq!(
+        overlapping.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeeTiers
+    );
+
+    // Retail pays 2%, B2B orders of 1,000+ pay 0.5%
+    setup_result.settings_client.set_fee_tiers(
+        admin,
+        &vec![
+            env,
+            FeeTier {
+                min_amount: 0,
+                max_amount: 1_000,
+                fee_percent: 2000,
+            },
+            FeeTier {
+                min_amount: 1_000,
+                max_amount: i128::MAX,
+                fee_percent: 500,
+            },
+        ],
+    );
+
+    let retail_amount = 100_i128;
+    let b2b_amount = 10_000_i128;
+    setup_result.token_client.mint(
+        &setup_result.addresses.sender,
+        &(retail_amount + b2b_amount),
+    );
+
+    let retail_id = Bytes::from_array(env, &[19u8; 32]);
+    let b2b_id = Bytes::from_array(env, &[20u8; 32]);
+    let mut order_params = OrderParams {
+        order_id: retail_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: retail_amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    order_params.order_id = b2b_id.clone();
+    order_params.amount = b2b_amount;
+    order_params.temporary_wallet_address = Address::generate(env);
+    setup_result.lp_client.create_order(&order_params);
+
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_order_info(&retail_id)
+            .protocol_fee,
+        2
+    );
+    assert_eq!(
+        setup_result.lp_client.get_order_info(&b2b_id).protocol_fee,
+        50
+    );
+
+    // Partial settlement keeps the B2B tier even though the chunk is smaller
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &b2b_id,
+        &setup_result.addresses.lp_node,
+        &5_000_i128,
+    );
+    setup_result.lp_client.execute_settlement_transfer(&b2b_id);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.lp_node),
+        498
+    );
+}
+
+#[test]
+fn test_token_fee_floor_and_cap() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let usdc = &setup_result.usdc_asset;
+
+    let invalid = setup_result
+        .settings_client
+        .try_set_token_fee_bounds(admin, usdc, &20_i128, &5_i128);
+    assert_eq!(
+        invalid.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidParameter
+    );
+
+    setup_result
+        .settings_client
+        .set_token_fee_bounds(admin, usdc, &5_i128, &20_i128);
+    let bounds = setup_result
+        .settings_client
+        .get_token_fee_bounds(usdc)
+        .unwrap();
+    assert_eq!((bounds.min_fee, bounds.max_fee), (5, 20));
+
+    let small_amount = 100_i128;
+    let whale_amount = 10_000_i128;
+    setup_result.token_client.mint(
+        &setup_result.addresses.sender,
+        &(small_amount + whale_amount),
+    );
+
+    let small_id = Bytes::from_array(env, &[21u8; 32]);
+    let whale_id = Bytes::from_array(env, &[22u8; 32]);
+    let whale_lp = Address::generate(env);
+    let mut order_params = OrderParams {
+        order_id: small_id.clone(),
+        token: usdc.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: small_amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    order_params.order_id = whale_id.clone();
+    order_params.amount = whale_amount;
+    order_params.temporary_wallet_address = Address::generate(env);
+    setup_result.lp_client.create_order(&order_params);
+
+    // 1% of 100 is raised to the floor, 1% of 10,000 is capped
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_order_info(&small_id)
+            .protocol_fee,
+        5
+    );
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_order_info(&whale_id)
+            .protocol_fee,
+        20
+    );
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &small_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&small_id);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.lp_node),
+        95
+    );
+
+    // Each chunk pays its share of the capped order fee
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &whale_id,
+        &whale_lp,
+        &50_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&whale_id);
+    assert_eq!(setup_result.lp_client.get_token_balance(&whale_lp), 4_990);
+
+    // Uneven remaining chunks still add up to exactly the order fee
+    for settle_percent in [33_333_i128, 16_667_i128] {
+        setup_result.lp_client.settle(
+            &setup_result.addresses.relayer,
+            &whale_id,
+            &whale_lp,
+            &settle_percent,
+        );
+        setup_result
+            .lp_client
+            .execute_settlement_transfer(&whale_id);
+    }
+    assert_eq!(setup_result.lp_client.get_token_balance(&whale_lp), 9_980);
+}
+
+#[test]
+fn test_settlement_chunks_split_fee_fixed_at_creation() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let lp_node = &setup_result.addresses.lp_node;
+
+    let amount = 10_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[52u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+
+    // A later fee change does not reach an order that already exists
+    setup_result
+        .settings_client
+        .update_protocol_fee(admin, &5_000_i64);
+
+    for settle_percent in [33_333_i128, 33_333_i128, 33_334_i128] {
+        setup_result.lp_client.settle(
+            &setup_result.addresses.relayer,
+            &order_id,
+            lp_node,
+            &settle_percent,
+        );
+        setup_result
+            .lp_client
+            .execute_settlement_transfer(&order_id);
+    }
+
+    // The chunks pay exactly the 1% fixed at creation, without rounding drift
+    let order = setup_result.lp_client.get_order_info(&order_id);
+    assert_eq!(order.protocol_fee, 100);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(lp_node),
+        amount - 100
+    );
+}
+
+#[test]
+fn test_lp_fee_override_with_expiry() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let partner = Address::generate(env);
+    let now = env.ledger().timestamp();
+
+    // Strategic partner pays 0.2% until the override expires
+    setup_result
+        .settings_client
+        .set_lp_fee_override(admin, &partner, &200_i64, &Some(now + 1_000));
+    assert_eq!(
+        setup_result.settings_client.get_active_lp_fee(&partner),
+        Some(200)
+    );
+
+    let amount = 10_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[23u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &partner,
+        &50_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(setup_result.lp_client.get_token_balance(&partner), 4_990);
+
+    // After expiry the regular 1% applies again
+    env.ledger().set_timestamp(now + 1_001);
+    assert_eq!(
+        setup_result.settings_client.get_active_lp_fee(&partner),
+        None
+    );
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &partner,
+        &50_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(&partner),
+        4_990 + 4_950
+    );
+}
+
+#[test]
+fn test_lp_fee_override_respects_token_fee_bounds() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let partner = Address::generate(env);
+
+    // A 0.2% override on 10,000 is 20, below the token's 60 floor
+    setup_result
+        .settings_client
+        .set_token_fee_bounds(admin, &setup_result.usdc_asset, &60, &1_000);
+    setup_result
+        .settings_client
+        .set_lp_fee_override(admin, &partner, &200_i64, &None);
+
+    let amount = 10_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[31u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    // Each half pays its share of the floor rather than the override
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &partner,
+        &50_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(setup_result.lp_client.get_token_balance(&partner), 4_970);
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &partner,
+        &50_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(&partner),
+        amount - 60
+    );
+}
+
+#[test]
+fn test_fee_distribution_splits_protocol_fee() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let treasury = setup_result.settings_client.get_treasury_address();
+    let insurance_fund = Address::generate(env);
+
+    // Weights must sum to 100%
+    let result = setup_result.settings_client.try_set_fee_distribution(&vec![
+        env,
+        FeeRecipient {
+            recipient: treasury.clone(),
+            weight_bps: 70_000,
+        },
+    ]);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeeDistribution
+    );
+
+    setup_result.settings_client.set_fee_distribution(&vec![
+        env,
+        FeeRecipient {
+            recipient: treasury.clone(),
+            weight_bps: 70_000,
+        },
+        FeeRecipient {
+            recipient: insurance_fund.clone(),
+            weight_bps: 30_000,
+        },
+    ]);
+
+    let amount = 100_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[24u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+
+    // 1% protocol fee of 1_000 split 70/30
+    assert_eq!(setup_result.lp_client.get_token_balance(&treasury), 700);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(&insurance_fund),
+        300
+    );
+}
+
+#[test]
+fn test_volume_limits_and_circuit_breaker() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let usdc = setup_result.usdc_asset.clone();
+    let now = env.ledger().timestamp();
+
+    // 10_000 per hour for USDC, and a global breaker at 15_000 per day
+    setup_result.settings_client.set_volume_limit(
+        admin,
+        &Some(usdc.clone()),
+        &VolumeLimit {
+            max_volume: 10_000,
+            window_seconds: 3_600,
+            auto_pause_at: None,
+        },
+    );
+    setup_result.settings_client.set_volume_limit(
+        admin,
+        &None,
+        &VolumeLimit {
+            max_volume: 20_000,
+            window_seconds: 86_400,
+            auto_pause_at: Some(15_000),
+        },
+    );
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &30_000_i128);
+    let order_params = |id: u8, amount: i128| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: usdc.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+
+    setup_result
+        .lp_client
+        .create_order(&order_params(25, 8_000));
+    let result = setup_result
+        .lp_client
+        .try_create_order(&order_params(26, 3_000));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::VolumeLimitExceeded
+    );
+
+    // The first order rolls out of the token window an hour after its 5-minute bucket
+    // closes; global volume reaches the breaker threshold
+    env.ledger().set_timestamp(now + 3_600 + 300);
+    setup_result
+        .lp_client
+        .create_order(&order_params(26, 8_000));
+    assert!(setup_result.lp_client.is_circuit_breaker_tripped(&None));
+    assert!(!setup_result
+        .lp_client
+        .is_circuit_breaker_tripped(&Some(usdc.clone())));
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_volume_window(&None)
+            .unwrap()
+            .volume,
+        16_000
+    );
+
+    let result = setup_result
+        .lp_client
+        .try_create_order(&order_params(27, 1_000));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::CircuitBreakerTripped
+    );
+
+    setup_result.lp_client.reset_circuit_breaker(&None);
+    setup_result
+        .lp_client
+        .create_order(&order_params(27, 1_000));
+}
+
+#[test]
+fn test_volume_limit_rolls_across_window_boundary() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let usdc = setup_result.usdc_asset.clone();
+
+    setup_result.settings_client.set_volume_limit(
+        admin,
+        &Some(usdc.clone()),
+        &VolumeLimit {
+            max_volume: 10_000,
+            window_seconds: 3_600,
+            auto_pause_at: None,
+        },
+    );
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &30_000_i128);
+    let order_params = |id: u8, amount: i128| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: usdc.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+
+    env.ledger().set_timestamp(1_000);
+    setup_result
+        .lp_client
+        .create_order(&order_params(43, 1_000));
+    env.ledger().set_timestamp(4_590);
+    setup_result
+        .lp_client
+        .create_order(&order_params(44, 9_000));
+
+    // A resetting window opened at 1,000 would accept a full 10,000 again from 4,600
+    env.ledger().set_timestamp(4_600);
+    let result = setup_result
+        .lp_client
+        .try_create_order(&order_params(45, 9_000));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::VolumeLimitExceeded
+    );
+
+    // Only the early order has rolled out of the trailing hour
+    env.ledger().set_timestamp(5_000);
+    setup_result
+        .lp_client
+        .create_order(&order_params(45, 1_000));
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_volume_window(&Some(usdc.clone()))
+            .unwrap()
+            .volume,
+        10_000
+    );
+
+    env.ledger().set_timestamp(8_400);
+    setup_result
+        .lp_client
+        .create_order(&order_params(46, 9_000));
+}
+
+#[test]
+fn test_token_circuit_breaker_only_halts_its_token() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let usdc = setup_result.usdc_asset.clone();
+    let (eurc_asset, eurc_client) = create_token_contract(env, admin);
+    setup_result
+        .settings_client
+        .add_supported_token(&eurc_asset);
+
+    setup_result.settings_client.set_volume_limit(
+        admin,
+        &Some(usdc.clone()),
+        &VolumeLimit {
+            max_volume: 10_000,
+            window_seconds: 3_600,
+            auto_pause_at: Some(5_000),
+        },
+    );
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    eurc_client.mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_params = |id: u8, token: &Address| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: token.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 5_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+
+    setup_result
+        .lp_client
+        .create_order(&order_params(47, &usdc));
+    assert!(setup_result
+        .lp_client
+        .is_circuit_breaker_tripped(&Some(usdc.clone())));
+    assert!(!setup_result.lp_client.is_circuit_breaker_tripped(&None));
+
+    let result = setup_result
+        .lp_client
+        .try_create_order(&order_params(48, &usdc));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::CircuitBreakerTripped
+    );
+    setup_result
+        .lp_client
+        .create_order(&order_params(49, &eurc_asset));
+
+    // Resetting the global breaker leaves the token breaker in place
+    setup_result.lp_client.reset_circuit_breaker(&None);
+    assert!(setup_result
+        .lp_client
+        .is_circuit_breaker_tripped(&Some(usdc.clone())));
+    setup_result
+        .lp_client
+        .reset_circuit_breaker(&Some(usdc.clone()));
+    setup_result
+        .lp_client
+        .create_order(&order_params(48, &usdc));
+}
+
+#[test]
+fn test_volume_limit_window_is_bounded() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let usdc = setup_result.usdc_asset.clone();
+
+    let result = setup_result.settings_client.try_set_volume_limit(
+        admin,
+        &Some(usdc.clone()),
+        &VolumeLimit {
+            max_volume: 10_000,
+            window_seconds: MAX_VOLUME_WINDOW + 1,
+            auto_pause_at: None,
+        },
+    );
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidVolumeLimit
+    );
+
+    // A limit stored before the cap existed must not overflow the window end
+    env.as_contract(&setup_result.settings_client.address, || {
+        env.storage().persistent().set(
+            &liquidity_manager::storage::DataKey::VolumeLimit(Some(usdc.clone())),
+            &VolumeLimit {
+                max_volume: 10_000,
+                window_seconds: u64::MAX,
+                auto_pause_at: None,
+            },
+        );
+    });
+    env.ledger().set_timestamp(1_000);
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &20_000_i128);
+    let order_params = |id: u8| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: usdc.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 6_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params(41));
+    let result = setup_result.lp_client.try_create_order(&order_params(42));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::VolumeLimitExceeded
+    );
+}
+
+#[test]
+fn test_compliance_screening() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let sender = &setup_result.addresses.sender;
+    let compliance_officer = Address::generate(env);
+
+    setup_result
+        .settings_client
+        .grant_role(&Role::Compliance, &compliance_officer);
+    setup_result.token_client.mint(sender, &20_000_i128);
+    let order_params = |id: u8| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: setup_result.usdc_asset.clone(),
+        sender: sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params(28));
+
+    // Allowlist-only mode rejects unscreened senders
+    setup_result
+        .settings_client
+        .set_allowlist_only(&compliance_officer, &true);
+    let result = setup_result.lp_client.try_create_order(&order_params(29));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::SenderNotAllowed
+    );
+    setup_result.settings_client.update_allowlist(
+        &compliance_officer,
+        &vec![env, sender.clone()],
+        &true,
+    );
+    assert!(setup_result.settings_client.is_sender_allowed(sender));
+
+    // Denylisting overrides the allowlist and restricts refund destinations
+    setup_result.settings_client.update_denylist(
+        &compliance_officer,
+        &vec![env, sender.clone()],
+        &true,
+    );
+    let result = setup_result.lp_client.try_create_order(&order_params(29));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::SenderNotAllowed
+    );
+
+    let order_id = Bytes::from_array(env, &[28u8; 32]);
+    let relayer = &setup_result.addresses.relayer;
+    let result = setup_result
+        .lp_client
+        .try_refund(relayer, &order_id, &0_i128);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::RefundAddressNotApproved
+    );
+
+    setup_result
+        .settings_client
+        .update_approved_refund_addresses(
+            admin,
+            &vec![env, setup_result.addresses.refund_address.clone()],
+            &true,
+        );
+    setup_result.lp_client.refund(relayer, &order_id, &0_i128);
+    setup_result.lp_client.execute_refund_transfer(&order_id);
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.refund_address),
+        10_000
+    );
+}
+
+#[test]
+fn test_compliance_redirects_stuck_refund() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let sender = &setup_result.addresses.sender;
+    let compliance_officer = Address::generate(env);
+    let recovery_wallet = Address::generate(env);
+
+    setup_result
+        .settings_client
+        .grant_role(&Role::Compliance, &compliance_officer);
+    setup_result.token_client.mint(sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[50u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: Some(env.ledger().timestamp() + 100),
+    });
+
+    // Sender is denylisted after the expired refund was staged
+    env.ledger().set_timestamp(env.ledger().timestamp() + 100);
+    setup_result.lp_client.refund_expired(&order_id);
+    setup_result.settings_client.update_denylist(
+        &compliance_officer,
+        &vec![env, sender.clone()],
+        &true,
+    );
+    let result = setup_result
+        .lp_client
+        .try_execute_refund_transfer(&order_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::RefundAddressNotApproved
+    );
+
+    let result = setup_result.lp_client.try_set_refund_destination(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &recovery_wallet,
+    );
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Unauthorized
+    );
+
+    setup_result
+        .lp_client
+        .set_refund_destination(&compliance_officer, &order_id, &recovery_wallet);
+    assert_eq!(
+        setup_result.lp_client.get_refund_destination(&order_id),
+        Some(recovery_wallet.clone())
+    );
+    setup_result.lp_client.execute_refund_transfer(&order_id);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(&recovery_wallet),
+        10_000
+    );
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_token_balance(&setup_result.addresses.refund_address),
+        0
+    );
+
+    let result = setup_result.lp_client.try_set_refund_destination(
+        &compliance_officer,
+        &order_id,
+        &recovery_wallet,
+    );
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderRefunded
+    );
+}
+
+#[test]
+fn test_freeze_order_blocks_settlement_and_refund() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let compliance_officer = Address::generate(env);
+    setup_result
+        .settings_client
+        .grant_role(&Role::Compliance, &compliance_officer);
+
+    let amount = 10_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[30u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, lp_node, &50_000_i128);
+
+    // Only Compliance role holders may freeze
+    let result = setup_result
+        .lp_client
+        .try_freeze_order(relayer, &order_id, &1_u32);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Unauthorized
+    );
+
+    setup_result
+        .lp_client
+        .freeze_order(&compliance_officer, &order_id, &1_u32);
+    assert!(setup_result.lp_client.is_order_frozen(&order_id));
+
+    let frozen = crate::error::ContractError::OrderFrozen;
+    let result = setup_result
+        .lp_client
+        .try_execute_settlement_transfer(&order_id);
+    assert_eq!(result.err().unwrap().unwrap(), frozen);
+    let result = setup_result
+        .lp_client
+        .try_settle(relayer, &order_id, lp_node, &50_000_i128);
+    assert_eq!(result.err().unwrap().unwrap(), frozen);
+    let result = setup_result
+        .lp_client
+        .try_refund(relayer, &order_id, &0_i128);
+    assert_eq!(result.err().unwrap().unwrap(), frozen);
+
+    setup_result
+        .lp_client
+        .unfreeze_order(&compliance_officer, &order_id, &2_u32);
+    let result = setup_result
+        .lp_client
+        .try_unfreeze_order(&compliance_officer, &order_id, &2_u32);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderNotFrozen
+    );
+
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(setup_result.lp_client.get_token_balance(lp_node), 4_950);
+}
+
+#[test]
+fn test_admin_freezes_order_until_signers_configured() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[51u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+
+    // Without signers the manager admin acts for Compliance
+    setup_result
+        .lp_client
+        .freeze_order(admin, &order_id, &1_u32);
+    assert!(setup_result.lp_client.is_order_frozen(&order_id));
+
+    setup_result.settings_client.set_signers(
+        &vec![env, Address::generate(env), Address::generate(env)],
+        &2_u32,
+    );
+    let result = setup_result
+        .lp_client
+        .try_unfreeze_order(admin, &order_id, &2_u32);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Unauthorized
+    );
+}
+
+#[test]
+fn test_guardian_can_pause_but_not_unpause() {
+    let setup_result = setup();
+    let guardian = Address::generate(&setup_result.env);
+
+    let result = setup_result.settings_client.try_pause(&guardian);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+
+    setup_result
+        .settings_client
+        .grant_role(&Role::Guardian, &guardian);
+    setup_result.settings_client.pause(&guardian);
+    assert!(setup_result.settings_client.is_paused());
+
+    let result = setup_result.settings_client.try_unpause(&guardian);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+
+    setup_result
+        .settings_client
+        .unpause(&setup_result.addresses.admin);
+    assert!(!setup_result.settings_client.is_paused());
+
+    // Removed guardians lose the ability to pause
+    setup_result
+        .settings_client
+        .revoke_role(&Role::Guardian, &guardian);
+    let result = setup_result.settings_client.try_pause(&guardian);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::Unauthorized
+    );
+}
+
+#[test]
+fn test_relayer_rotation_grace_period() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let old_relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let new_relayer = Address::generate(env);
+    env.ledger().set_sequence_number(1_000);
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &30_000_i128);
+    let create = |id: u8| {
+        let order_id = Bytes::from_array(env, &[id; 32]);
+        setup_result.lp_client.create_order(&OrderParams {
+            order_id: order_id.clone(),
+            token: setup_result.usdc_asset.clone(),
+            sender: setup_result.addresses.sender.clone(),
+            temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+            amount: 10_000,
+            rate: 9500_i64,
+            refund_address: setup_result.addresses.refund_address.clone(),
+            message_hash: String::from_str(env, "hash123"),
+            expires_at: None,
+        });
+        order_id
+    };
+    let first = create(31);
+    let second = create(32);
+    let third = create(33);
+
+    setup_result
+        .settings_client
+        .rotate_relayer(&new_relayer, &100_u32);
+    assert_eq!(
+        setup_result.settings_client.get_relayer_address(),
+        new_relayer
+    );
+    assert_eq!(
+        setup_result
+            .settings_client
+            .get_retiring_relayer()
+            .unwrap()
+            .expires_at_ledger,
+        1_100
+    );
+
+    // Both keys are accepted during the grace period
+    setup_result
+        .lp_client
+        .settle(old_relayer, &first, lp_node, &100_000_i128);
+    setup_result
+        .lp_client
+        .settle(&new_relayer, &second, lp_node, &100_000_i128);
+
+    // The old key expires automatically afterwards
+    env.ledger().set_sequence_number(1_101);
+    assert!(setup_result
+        .settings_client
+        .get_retiring_relayer()
+        .is_none());
+    let result = setup_result
+        .lp_client
+        .try_refund(old_relayer, &third, &0_i128);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Unauthorized
+    );
+    setup_result.lp_client.refund(&new_relayer, &third, &0_i128);
+
+    // A relayer granted the role in its own right keeps it after rotating away
+    let standby = Address::generate(env);
+    setup_result
+        .settings_client
+        .grant_role(&Role::Relayer, &standby);
+    setup_result
+        .settings_client
+        .rotate_relayer(&standby, &0_u32);
+    assert!(!setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &new_relayer));
+
+    // Only one rotation may be in its grace period at a time
+    let replacement = Address::generate(env);
+    let result = setup_result
+        .settings_client
+        .try_rotate_relayer(&replacement, &u32::MAX);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::RotationInProgress
+    );
+
+    env.ledger().set_sequence_number(1_102);
+    setup_result
+        .settings_client
+        .rotate_relayer(&replacement, &u32::MAX);
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &standby));
+    assert_eq!(
+        setup_result
+            .settings_client
+            .get_retiring_relayer()
+            .unwrap()
+            .expires_at_ledger,
+        u32::MAX
+    );
+}
+
+#[test]
+fn test_relayer_role_follows_primary_relayer() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let standby = Address::generate(env);
+    let replacement = Address::generate(env);
+    setup_result
+        .settings_client
+        .grant_role(&Role::Relayer, &standby);
+
+    // The previous primary loses the role it only held as primary
+    setup_result
+        .settings_client
+        .update_protocol_address(&ProtocolAddressType::Aggregator, &standby);
+    assert!(!setup_result
+        .settings_client
+        .has_role(&Role::Relayer, relayer));
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &standby));
+
+    // A primary that held the role in its own right keeps it after being replaced
+    setup_result
+        .settings_client
+        .update_config(&ProtocolConfigUpdate {
+            protocol_fee_percent: None,
+            treasury: None,
+            relayer: Some(replacement.clone()),
+            paused: None,
+            supported_tokens: None,
+        });
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &standby));
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &replacement));
+
+    // The replacement only held the role as primary, so it is dropped on the next change
+    setup_result
+        .settings_client
+        .update_protocol_address(&ProtocolAddressType::Aggregator, relayer);
+    assert!(!setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &replacement));
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, relayer));
+}
+
+#[test]
+fn test_update_config_applies_partial_update() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let new_treasury = Address::generate(env);
+    let new_relayer = Address::generate(env);
+    let (other_token, _) = create_token_contract(env, &setup_result.addresses.admin);
+
+    let config = setup_result.settings_client.get_config();
+    assert_eq!(config.protocol_fee_percent, 1000);
+    assert_eq!(config.max_bps, 100_000);
+    assert_eq!(
+        config.supported_tokens,
+        vec![env, setup_result.usdc_asset.clone()]
+    );
+
+    setup_result
+        .settings_client
+        .update_config(&ProtocolConfigUpdate {
+            protocol_fee_percent: Some(750),
+            treasury: Some(new_treasury.clone()),
+            relayer: Some(new_relayer.clone()),
+            paused: None,
+            supported_tokens: Some(vec![env, other_token.clone()]),
+        });
+
+    let config = setup_result.settings_client.get_config();
+    assert_eq!(config.protocol_fee_percent, 750);
+    assert_eq!(config.treasury, new_treasury);
+    assert_eq!(config.relayer, new_relayer);
+    assert!(!config.paused);
+    assert!(!setup_result
+        .settings_client
+        .is_token_supported(&setup_result.usdc_asset));
+    assert!(setup_result
+        .settings_client
+        .is_token_supported(&other_token));
+    assert!(setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &new_relayer));
+    assert!(!setup_result
+        .settings_client
+        .has_role(&Role::Relayer, &setup_result.addresses.relayer));
+
+    // An invalid field rejects the whole update
+    let result = setup_result
+        .settings_client
+        .try_update_config(&ProtocolConfigUpdate {
+            protocol_fee_percent: Some(100_001),
+            treasury: None,
+            relayer: None,
+            paused: Some(true),
+            supported_tokens: None,
+        });
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeePercent
+    );
+    assert!(!setup_result.settings_client.is_paused());
+
+    // With a timelock the update goes through the queue, validated up front
+    let delay = 3_600_u64;
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateTimelockDelay(delay));
+    setup_result.settings_client.execute_action(&id);
+
+    let update = ProtocolConfigUpdate {
+        protocol_fee_percent: Some(500),
+        treasury: None,
+        relayer: None,
+        paused: None,
+        supported_tokens: None,
+    };
+    let result = setup_result.settings_client.try_update_config(&update);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::TimelockRequired
+    );
+    let result = setup_result
+        .settings_client
+        .try_queue_action(&GovernanceAction::UpdateConfig(ProtocolConfigUpdate {
+            protocol_fee_percent: None,
+            ..update.clone()
+        }));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidParameter
+    );
+
+    let id = setup_result
+        .settings_client
+        .queue_action(&GovernanceAction::UpdateConfig(update));
+    env.ledger().set_timestamp(env.ledger().timestamp() + delay);
+    setup_result.settings_client.execute_action(&id);
+    assert_eq!(
+        setup_result
+            .settings_client
+            .get_config()
+            .protocol_fee_percent,
+        500
+    );
+}
+
+#[test]
+fn test_typed_versioned_events() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let lp_node = &setup_result.addresses.lp_node;
+
+    let amount = 10_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[34u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            Symbol::new(env, "OrderCreated"),
+            order_id.clone(),
+            setup_result.addresses.sender.clone()
+        )
+            .into_val(env)
+    );
+    let created = OrderCreatedEvent::try_from_val(env, &data).unwrap();
+    assert_eq!(created.version, EVENT_VERSION);
+    assert_eq!(created.token, setup_result.usdc_asset);
+    assert_eq!(created.amount, amount);
+    assert_eq!(created.protocol_fee, 100);
+
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        lp_node,
+        &100_000_i128,
+    );
+    let (_, _, data) = env.events().all().last().unwrap();
+    let settled = OrderSettledEvent::try_from_val(env, &data).unwrap();
+    assert_eq!(settled.version, SETTLEMENT_EVENT_VERSION);
+    assert_eq!(settled.recipient, lp_node.clone());
+    assert_eq!(settled.gross_amount, 10_000);
+    assert_eq!(settled.protocol_fee, 100);
+    assert_eq!(settled.net_amount, 9_900);
+}
+
+#[test]
+fn test_settlement_and_refund_event_breakdown() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let lp_node = &setup_result.addresses.lp_node;
+    let relayer = &setup_result.addresses.relayer;
+    let refund_address = &setup_result.addresses.refund_address;
+    let usdc = &setup_result.usdc_asset;
+
+    let amount = 10_000_i128;
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &amount);
+    let order_id = Bytes::from_array(env, &[35u8; 32]);
+    let order_params = OrderParams {
+        order_id: order_id.clone(),
+        token: usdc.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount,
+        rate: 9500_i64,
+        refund_address: refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params);
+
+    // 40% chunk: 4_000 gross, 1% fee
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, lp_node, &40_000_i128);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let expected = OrderSettledEvent {
+        version: SETTLEMENT_EVENT_VERSION,
+        order_id: order_id.clone(),
+        sequence: 0,
+        token: usdc.clone(),
+        recipient: lp_node.clone(),
+        settle_percent: 40_000,
+        gross_amount: 4_000,
+        protocol_fee: 40,
+        net_amount: 3_960,
+        remaining_amount: 6_000,
+        remaining_bps: 60_000,
+    };
+    assert_eq!(
+        OrderSettledEvent::try_from_val(env, &data).unwrap(),
+        expected
+    );
+
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let transferred = SettlementTransferredEvent::try_from_val(env, &data).unwrap();
+    assert_eq!(transferred.version, SETTLEMENT_EVENT_VERSION);
+    assert_eq!(transferred.sequence, expected.sequence);
+    assert_eq!(transferred.recipient, lp_node.clone());
+    assert_eq!(transferred.gross_amount, expected.gross_amount);
+    assert_eq!(transferred.protocol_fee, expected.protocol_fee);
+    assert_eq!(transferred.net_amount, expected.net_amount);
+    assert_eq!(transferred.remaining_bps, 60_000);
+
+    // Refund of the unsettled remainder
+    setup_result.lp_client.refund(relayer, &order_id, &50_i128);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let refunded = OrderRefundedEvent::try_from_val(env, &data).unwrap();
+    assert_eq!(refunded.version, REFUND_EVENT_VERSION);
+    assert_eq!(refunded.token, usdc.clone());
+    assert_eq!(refunded.recipient, refund_address.clone());
+    assert_eq!(refunded.gross_amount, 6_000);
+    assert_eq!(refunded.protocol_fee, 50);
+    assert_eq!(refunded.net_amount, 5_950);
+    assert_eq!(refunded.remaining_amount, 0);
+    assert_eq!(refunded.remaining_bps, 0);
+
+    setup_result.lp_client.execute_refund_transfer(&order_id);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let refund_transferred = RefundTransferredEvent::try_from_val(env, &data).unwrap();
+    assert_eq!(refund_transferred.version, REFUND_EVENT_VERSION);
+    assert_eq!(refund_transferred.recipient, refund_address.clone());
+    assert_eq!(refund_transferred.net_amount, 5_950);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(refund_address),
+        5_950
+    );
+}
+
+#[test]
+fn test_expired_order_permissionless_refund() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let refund_address = &setup_result.addresses.refund_address;
+    let now = env.ledger().timestamp();
+
+    let result = setup_result
+        .settings_client
+        .try_set_max_order_duration(&(MAX_ORDER_DURATION + 1));
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidParameter
+    );
+    setup_result.settings_client.set_max_order_duration(&3_600);
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[36u8; 32]);
+    let order_params = |expires_at: Option<u64>| OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at,
+    };
+
+    // Expiry must be in the future and within the maximum order duration
+    for expires_at in [now, now + 3_601] {
+        let result = setup_result
+            .lp_client
+            .try_create_order(&order_params(Some(expires_at)));
+        assert_eq!(
+            result.err().unwrap().unwrap(),
+            crate::error::ContractError::InvalidExpiry
+        );
+    }
+
+    setup_result
+        .lp_client
+        .create_order(&order_params(Some(now + 1_000)));
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, lp_node, &40_000_i128);
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+
+    let result = setup_result.lp_client.try_refund_expired(&order_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderNotExpired
+    );
+
+    // After expiry settlement stops and anyone can refund the remainder fee-free
+    env.ledger().set_timestamp(now + 1_000);
+    let result = setup_result
+        .lp_client
+        .try_settle(relayer, &order_id, lp_node, &60_000_i128);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderExpired
+    );
+
+    setup_result.lp_client.refund_expired(&order_id);
+    setup_result.lp_client.execute_refund_transfer(&order_id);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(refund_address),
+        6_000
+    );
+}
+
+#[test]
+fn test_order_expiry_defaults_to_max_duration() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let now = env.ledger().timestamp();
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[37u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+
+    assert_eq!(
+        setup_result.lp_client.get_order_expiry(&order_id),
+        Some(now + liquidity_manager::liquidity_manager::DEFAULT_MAX_ORDER_DURATION)
+    );
+
+    // Orders stored before expiry existed have no expiry key and never expire
+    env.as_contract(&setup_result.lp_client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::OrderExpiry(order_id.clone()));
+    });
+    env.ledger()
+        .set_timestamp(now + liquidity_manager::liquidity_manager::DEFAULT_MAX_ORDER_DURATION);
+    let result = setup_result.lp_client.try_refund_expired(&order_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderNotExpired
+    );
+    setup_result.lp_client.settle(
+        &setup_result.addresses.relayer,
+        &order_id,
+        &setup_result.addresses.lp_node,
+        &100_000_i128,
+    );
+}
+
+#[test]
+fn test_sender_cancels_untouched_order() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let admin = &setup_result.addresses.admin;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let refund_address = &setup_result.addresses.refund_address;
+
+    let result = setup_result
+        .settings_client
+        .try_set_cancellation_fee(admin, &100_001);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        liquidity_manager::error::ContractError::InvalidFeePercent
+    );
+    setup_result
+        .settings_client
+        .set_cancellation_fee(admin, &500);
+    assert_eq!(setup_result.settings_client.get_cancellation_fee(), 500);
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &20_000_i128);
+    let order_params = |id: u8| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+
+    // Once settlement has started the sender can no longer cancel
+    let settled_id = Bytes::from_array(env, &[38u8; 32]);
+    setup_result.lp_client.create_order(&order_params(38));
+    setup_result
+        .lp_client
+        .settle(relayer, &settled_id, lp_node, &40_000_i128);
+    let result = setup_result.lp_client.try_cancel_order(&settled_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderPartiallySettled
+    );
+
+    let order_id = Bytes::from_array(env, &[39u8; 32]);
+    setup_result.lp_client.create_order(&order_params(39));
+    setup_result.lp_client.cancel_order(&order_id);
+    assert_eq!(
+        env.auths()[0].0,
+        setup_result.addresses.sender,
+        "sender must authorize the cancellation"
+    );
+
+    assert!(setup_result.lp_client.is_order_cancelled(&order_id));
+    assert!(setup_result.lp_client.get_order_info(&order_id).is_refunded);
+
+    let result = setup_result
+        .lp_client
+        .try_settle(relayer, &order_id, lp_node, &100_000_i128);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderCancelled
+    );
+
+    // 0.5% cancellation fee goes to the treasury, the rest back to the refund address
+    setup_result.lp_client.execute_refund_transfer(&order_id);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(refund_address),
+        9_950
+    );
+}
+
+#[test]
+fn test_concurrent_pending_settlements() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let other_lp = Address::generate(env);
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[40u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+
+    // Three chunks queued before any transfer runs; none overwrites another
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, lp_node, &20_000_i128);
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, &other_lp, &30_000_i128);
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, lp_node, &10_000_i128);
+
+    let result = setup_result
+        .lp_client
+        .try_settle(relayer, &order_id, &other_lp, &40_001_i128);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::InvalidSettlePercent
+    );
+
+    let pending = setup_result.lp_client.get_pending_settlements(&order_id);
+    assert_eq!(pending.len(), 3);
+    assert_eq!(pending.get(1).unwrap().sequence, 1);
+    assert_eq!(pending.get(1).unwrap().liquidity_provider, other_lp);
+
+    // Pay the middle chunk on its own
+    setup_result
+        .lp_client
+        .execute_settlement_sequence(&order_id, &1);
+    assert_eq!(setup_result.lp_client.get_token_balance(&other_lp), 2_970);
+    let result = setup_result
+        .lp_client
+        .try_execute_settlement_sequence(&order_id, &1);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::NoPendingSettlement
+    );
+
+    let pending = setup_result.lp_client.get_pending_settlements(&order_id);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap().sequence, 0);
+    assert_eq!(pending.get(1).unwrap().sequence, 2);
+
+    // Pay the rest at once
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(setup_result.lp_client.get_token_balance(lp_node), 2_970);
+    assert!(setup_result
+        .lp_client
+        .get_pending_settlements(&order_id)
+        .is_empty());
+    let result = setup_result
+        .lp_client
+        .try_execute_settlement_transfer(&order_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::NoPendingSettlement
+    );
+}
+
+#[test]
+fn test_legacy_pending_settlement_is_sequence_zero() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let other_lp = Address::generate(env);
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[43u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, lp_node, &40_000_i128);
+
+    // Rewrite the chunk the way it was stored before settlements were sequenced
+    env.as_contract(&setup_result.lp_client.address, || {
+        let storage = env.storage().persistent();
+        let pending: PendingSettlement = storage
+            .get(&DataKey::SequencedSettlement(order_id.clone(), 0))
+            .unwrap();
+        storage.remove(&DataKey::SequencedSettlement(order_id.clone(), 0));
+        storage.remove(&DataKey::PendingSettlementSequences(order_id.clone()));
+        storage.remove(&DataKey::NextSettlementSequence(order_id.clone()));
+        storage.set(
+            &DataKey::PendingSettlement(order_id.clone()),
+            &LegacyPendingSettlement {
+                order_id: pending.order_id,
+                protocol_fee: pending.protocol_fee,
+                transfer_amount: pending.transfer_amount,
+                liquidity_provider: pending.liquidity_provider,
+                settle_percent: pending.settle_percent,
+            },
+        );
+    });
+
+    let pending = setup_result.lp_client.get_pending_settlements(&order_id);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap().sequence, 0);
+    let result = setup_result.lp_client.try_cancel_order(&order_id);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::OrderPartiallySettled
+    );
+
+    // New chunks queue behind the legacy one and both pay out
+    setup_result
+        .lp_client
+        .settle(relayer, &order_id, &other_lp, &60_000_i128);
+    let pending = setup_result.lp_client.get_pending_settlements(&order_id);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(1).unwrap().sequence, 1);
+
+    // The legacy chunk keeps the remainder it left, not the one after the new chunk
+    let legacy = pending.get(0).unwrap();
+    assert_eq!(
+        (legacy.remaining_amount, legacy.remaining_bps),
+        (6_000, 60_000)
+    );
+    let latest = pending.get(1).unwrap();
+    assert_eq!((latest.remaining_amount, latest.remaining_bps), (0, 0));
+
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    assert_eq!(setup_result.lp_client.get_token_balance(lp_node), 3_960);
+    assert_eq!(setup_result.lp_client.get_token_balance(&other_lp), 5_940);
+    assert!(setup_result
+        .lp_client
+        .get_pending_settlements(&order_id)
+        .is_empty());
+}
+
+#[test]
+fn test_batched_transfer_events_report_remainder_per_chunk() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[53u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+    for settle_percent in [25_000_i128, 35_000_i128, 40_000_i128] {
+        setup_result
+            .lp_client
+            .settle(relayer, &order_id, lp_node, &settle_percent);
+    }
+
+    // One transfer call pays all three chunks; each event reports its own remainder
+    setup_result
+        .lp_client
+        .execute_settlement_transfer(&order_id);
+    let mut remainders = vec![env];
+    let name = Symbol::new(env, "SettlementTransferred");
+    for (contract, topics, data) in env.events().all().iter() {
+        let topic = Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap();
+        if contract == setup_result.lp_client.address && topic == name {
+            let transferred = SettlementTransferredEvent::try_from_val(env, &data).unwrap();
+            remainders.push_back((transferred.remaining_amount, transferred.remaining_bps));
+        }
+    }
+    assert_eq!(
+        remainders,
+        vec![env, (7_500_i128, 75_000_i128), (4_000, 40_000), (0, 0)]
+    );
+}
+
+#[test]
+fn test_settle_and_refund_with_transfer_in_one_call() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+    let temporary_wallet = &setup_result.addresses.temporary_wallet;
+    let refund_address = &setup_result.addresses.refund_address;
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &10_000_i128);
+    let order_id = Bytes::from_array(env, &[41u8; 32]);
+    setup_result.lp_client.create_order(&OrderParams {
+        order_id: order_id.clone(),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    });
+
+    // 40% chunk paid out immediately, with both parties authorizing
+    setup_result
+        .lp_client
+        .settle_and_transfer(relayer, &order_id, lp_node, &40_000_i128);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| address == relayer));
+    assert!(auths.iter().any(|(address, _)| address == temporary_wallet));
+    assert_eq!(setup_result.lp_client.get_token_balance(lp_node), 3_960);
+    assert!(setup_result
+        .lp_client
+        .get_pending_settlements(&order_id)
+        .is_empty());
+
+    // A blocked transfer rolls the settlement back too
+    setup_result.settings_client.set_operation_paused(
+        &setup_result.addresses.admin,
+        &PauseOperation::SettlementTransfer,
+        &true,
+    );
+    let result =
+        setup_result
+            .lp_client
+            .try_settle_and_transfer(relayer, &order_id, lp_node, &10_000_i128);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::Paused
+    );
+    assert_eq!(
+        setup_result.lp_client.get_order_info(&order_id).current_bps,
+        60_000
+    );
+
+    setup_result
+        .lp_client
+        .refund_and_transfer(relayer, &order_id, &50_i128);
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(refund_address),
+        5_950
+    );
+    assert_eq!(
+        setup_result.lp_client.get_token_balance(temporary_wallet),
+        0
+    );
+}
+
+#[test]
+fn test_batch_settle_reports_per_item_results() {
+    let setup_result = setup();
+    let env = &setup_result.env;
+    let relayer = &setup_result.addresses.relayer;
+    let lp_node = &setup_result.addresses.lp_node;
+
+    setup_result
+        .token_client
+        .mint(&setup_result.addresses.sender, &20_000_i128);
+    let order_params = |id: u8| OrderParams {
+        order_id: Bytes::from_array(env, &[id; 32]),
+        token: setup_result.usdc_asset.clone(),
+        sender: setup_result.addresses.sender.clone(),
+        temporary_wallet_address: setup_result.addresses.temporary_wallet.clone(),
+        amount: 10_000,
+        rate: 9500_i64,
+        refund_address: setup_result.addresses.refund_address.clone(),
+        message_hash: String::from_str(env, "hash123"),
+        expires_at: None,
+    };
+    setup_result.lp_client.create_order(&order_params(42));
+    setup_result.lp_client.create_order(&order_params(43));
+    let first_order = Bytes::from_array(env, &[42u8; 32]);
+    let second_order = Bytes::from_array(env, &[43u8; 32]);
+    let missing_order = Bytes::from_array(env, &[44u8; 32]);
+
+    let instruction = |order_id: &Bytes, settle_percent: i128| SettlementInstruction {
+        order_id: order_id.clone(),
+        liquidity_provider: lp_node.clone(),
+        settle_percent,
+    };
+    let results = setup_result.lp_client.batch_settle(
+        relayer,
+        &vec![
+            env,
+            instruction(&first_order, 60_000),
+            instruction(&missing_order, 10_000),
+            // Exceeds what the first instruction left on the order
+            instruction(&first_order, 50_000),
+            instruction(&second_order, 100_000),
+        ],
+    );
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results.get(0).unwrap().sequence, Some(0));
+    assert_eq!(results.get(0).unwrap().error, None);
+    assert_eq!(
+        results.get(1).unwrap().error,
+        Some(crate::error::ContractError::OrderNotFound as u32)
+    );
+    assert_eq!(
+        results.get(2).unwrap().error,
+        Some(crate::error::ContractError::InvalidSettlePercent as u32)
+    );
+    assert_eq!(results.get(2).unwrap().sequence, None);
+    assert_eq!(results.get(3).unwrap().sequence, Some(0));
+
+    // Failed instructions leave their orders untouched
+    assert_eq!(
+        setup_result
+            .lp_client
+            .get_order_info(&first_order)
+            .current_bps,
+        40_000
+    );
+    assert!(
+        setup_result
+            .lp_client
+            .get_order_info(&second_order)
+            .is_fulfilled
+    );
+
+    let result = setup_result.lp_client.try_batch_settle(relayer, &vec![env]);
+    assert_eq!(
+        result.err().unwrap().unwrap(),
+        crate::error::ContractError::InvalidParameter
+    );
+}
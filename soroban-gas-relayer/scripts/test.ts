@@ -17,9 +17,8 @@ describe("Soroban Gas Relayer API Tests", () => {
       "1234123412341234123412341234123412341234123412341234123412349998",
     token: "GBBD47IF6LWK7P7MDEVSCWR7DPUWV3NY3DTQEVFL4NAT4AQH3ZLLFLA5",
     amount: "3",
-    sender_fee_recipient:
+    temporary_wallet_address:
       "GA3N5T6H7QL5RH66C3CVU6IRALPFCMKYX753OWJY6BOE3ESEXBOMV2KN",
-    sender_fee: "1",
     refund_address: "GA3N5T6H7QL5RH66C3CVU6IRALPFCMKYX753OWJY6BOE3ESEXBOMV2KN",
     rate: "9500",
     message_hash: "hash123",
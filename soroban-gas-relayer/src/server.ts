@@ -77,18 +77,24 @@ app.post("/createOrder", async (req, res) => {
       order_id,
       token,
       amount,
-      sender_fee_recipient,
-      sender_fee,
+      temporary_wallet_address,
       refund_address,
       rate,
       message_hash,
+      expires_at,
     } = orderParams;
 
-    if (!sender || !order_id || !token || amount === undefined) {
+    if (
+      !sender ||
+      !order_id ||
+      !token ||
+      amount === undefined ||
+      !temporary_wallet_address
+    ) {
       return res.status(400).json({
         success: false,
         message:
-          "Missing required order parameters (sender, order_id, token, amount)",
+          "Missing required order parameters (sender, order_id, token, amount, temporary_wallet_address)",
       });
     }
 
@@ -110,7 +116,7 @@ app.post("/createOrder", async (req, res) => {
     };
     validateAddress(sender, "sender");
     validateAddress(token, "token");
-    validateAddress(sender_fee_recipient || sender, "sender_fee_recipient");
+    validateAddress(temporary_wallet_address, "temporary_wallet_address");
     validateAddress(refund_address || sender, "refund_address");
 
     const hexRegex = /^(0x)?[0-9a-fA-F]{64}$/;
@@ -140,10 +146,14 @@ app.post("/createOrder", async (req, res) => {
 
     const amountBigInt = validateBigInt(amount, "amount", 1);
 
-    const senderFeeBigInt = validateBigInt(sender_fee || 0, "sender_fee", 0);
-
     const rateBigInt = validateBigInt(rate || 0, "rate");
 
+    // Omitted expiry lets the contract apply its maximum order duration
+    const expiresAtBigInt =
+      expires_at === undefined || expires_at === null
+        ? undefined
+        : validateBigInt(expires_at, "expires_at", 1);
+
     if (typeof message_hash !== "string" || message_hash.length === 0) {
       throw new Error("Invalid message_hash: must be a non-empty string");
     }
@@ -153,11 +163,11 @@ app.post("/createOrder", async (req, res) => {
       order_id,
       token,
       amount: amountBigInt,
-      sender_fee_recipient: sender_fee_recipient || sender,
-      sender_fee: senderFeeBigInt,
+      temporary_wallet_address,
       refund_address: refund_address || sender,
       rate: rateBigInt,
       message_hash,
+      expires_at: expiresAtBigInt,
     };
 
     const orderParamsScVal = SorobanUtils.parseOrderParams(params);
@@ -204,7 +214,9 @@ app.post("/settle", async (req, res): Promise<void> => {
       });
     }
 
+    // The caller is the relayer and must hold the Relayer role
     const args = [
+      SorobanUtils.convertToScVal(caller, "address"),
       SorobanUtils.convertToScVal(orderId, "bytes"),
       SorobanUtils.convertToScVal(liquidityProvider, "address"),
       SorobanUtils.convertToScVal(BigInt(settlePercent), "i128"),
@@ -245,7 +257,9 @@ app.post("/refund", async (req, res): Promise<void> => {
       });
     }
 
+    // The caller is the relayer and must hold the Relayer role
     const args = [
+      SorobanUtils.convertToScVal(caller, "address"),
       SorobanUtils.convertToScVal(orderId, "bytes"),
       SorobanUtils.convertToScVal(BigInt(fee), "i128"),
     ];
@@ -89,6 +89,18 @@ export class SorobanUtils {
           const bigIntValue = typeof value === "bigint" ? value : BigInt(value);
           return nativeToScVal(bigIntValue, { type: "i128" });
 
+        case "u64":
+          const bigIntU64Value =
+            typeof value === "bigint" ? value : BigInt(value);
+
+          if (
+            bigIntU64Value < BigInt(0) ||
+            bigIntU64Value > BigInt("18446744073709551615")
+          ) {
+            throw new Error(`u64 value out of range: ${bigIntU64Value}`);
+          }
+          return nativeToScVal(bigIntU64Value, { type: "u64" });
+
         case "i64":
           const bigIntI64Value =
             typeof value === "bigint" ? value : BigInt(value);
@@ -133,6 +145,13 @@ export class SorobanUtils {
         key: xdr.ScVal.scvSymbol("amount"),
         val: this.convertToScVal(params.amount, "i128"),
       }),
+      new xdr.ScMapEntry({
+        key: xdr.ScVal.scvSymbol("expires_at"),
+        val:
+          params.expires_at === undefined
+            ? xdr.ScVal.scvVoid()
+            : this.convertToScVal(params.expires_at, "u64"),
+      }),
       new xdr.ScMapEntry({
         key: xdr.ScVal.scvSymbol("message_hash"),
         val: this.convertToScVal(params.message_hash, "string"),
@@ -154,12 +173,8 @@ export class SorobanUtils {
         val: this.convertToScVal(params.sender, "address"),
       }),
       new xdr.ScMapEntry({
-        key: xdr.ScVal.scvSymbol("sender_fee"),
-        val: this.convertToScVal(params.sender_fee, "i128"),
-      }),
-      new xdr.ScMapEntry({
-        key: xdr.ScVal.scvSymbol("sender_fee_recipient"),
-        val: this.convertToScVal(params.sender_fee_recipient, "address"),
+        key: xdr.ScVal.scvSymbol("temporary_wallet_address"),
+        val: this.convertToScVal(params.temporary_wallet_address, "address"),
       }),
       new xdr.ScMapEntry({
         key: xdr.ScVal.scvSymbol("token"),
@@ -12,10 +12,10 @@ export interface OrderParams {
   sender: string;
   amount: bigint;
   rate: bigint;
-  sender_fee_recipient: string;
-  sender_fee: bigint;
+  temporary_wallet_address: string;
   refund_address: string;
   message_hash: string;
+  expires_at?: bigint;
 }
 
 export interface TransactionResult {
@@ -32,8 +32,7 @@ export interface OrderInfo {
   sender: string;
   token: string;
   amount: bigint;
-  sender_fee_recipient: string;
-  sender_fee: bigint;
+  temporary_wallet_address: string;
   protocol_fee: bigint;
   is_fulfilled: boolean;
   is_refunded: boolean;